@@ -35,7 +35,6 @@
 //! ```
 
 #[allow(unused, unused_imports)]
-
 use std::{fmt, ops::{Add, Sub, AddAssign, SubAssign, Mul, Div}};
 
 mod vecs;
@@ -47,6 +46,14 @@ mod tests {
 
     use super::{Vec2, Vec3};
 
+    /// a small deterministic generator of uniform values in [0, 1)
+    fn lcg(mut seed: u64) -> impl FnMut() -> f64 {
+        move || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 11) as f64 / (1u64 << 53) as f64
+        }
+    }
+
     #[test]
     fn vec2_equal() {
         assert_eq!(Vec2::new(5., -5.), Vec2::new(5., -5.));
@@ -57,6 +64,40 @@ mod tests {
         assert_ne!(Vec2::new(5., 6.), Vec2::new(6., 5.));
     }
 
+    #[test]
+    fn vec2_integer() {
+        let mut v: Vec2<i32> = Vec2::new(3, -4);
+        v.set(v.x() * 2, v.y());
+
+        assert_eq!(Vec2::new(6, -4), v);
+        assert_eq!("(6, -4)", v.to_string());
+    }
+
+    #[test]
+    fn vec2_integer_zero() {
+        let mut total = Vec2::<i32>::zero();
+        assert!(total.is_zero());
+
+        total.set(total.x() + 2, total.y() - 1);
+        assert!(!total.is_zero());
+    }
+
+    #[test]
+    fn vec2_debug() {
+        let v = Vec2::new(10, 2);
+
+        assert_eq!("Vec2 { x: 10, y: 2 }", format!("{:?}", v));
+        assert_eq!("Vec2 {\n    x: 10,\n    y:  2,\n}", format!("{:#?}", v));
+    }
+
+    #[test]
+    fn vec2_lexicographic_sort() {
+        let mut v = vec![Vec2::new(2, 1), Vec2::new(1, 5), Vec2::new(2, -3), Vec2::new(1, 2)];
+        v.sort();
+
+        assert_eq!(vec![Vec2::new(1, 2), Vec2::new(1, 5), Vec2::new(2, -3), Vec2::new(2, 1)], v);
+    }
+
     #[test]
     fn vec2_add() {
         assert_eq!(Vec2::new(5., 5.), Vec2::new(2., 3.) + Vec2::new(3., 2.));
@@ -67,11 +108,188 @@ mod tests {
         assert_eq!(Vec2::new(-5., 20.), Vec2::new(0., 30.) - Vec2::new(5., 10.));
     }
 
+    #[test]
+    fn vec2_add_scalar() {
+        assert_eq!(Vec2::new(2., 3.), Vec2::new(1., 2.) + 1.);
+    }
+
+    #[test]
+    fn vec2_sub_scalar() {
+        assert_eq!(Vec2::new(0., 1.), Vec2::new(1., 2.) - 1.);
+    }
+
+    #[test]
+    fn vec2_scalar_lhs() {
+        let v32 = Vec2::new(1_f32, 4.);
+        let v64 = Vec2::new(1_f64, 4.);
+
+        assert_eq!(v32 * 2., 2. * v32);
+        assert_eq!(v64 * 2., 2. * v64);
+        assert_eq!(Vec2::new(4_f32, 1.), 4. / v32);
+        assert_eq!(Vec2::new(4_f64, 1.), 4. / v64);
+    }
+
     #[test]
     fn vec2_dot() {
         assert_eq!(200., Vec2::new(10., 10.).dot(Vec2::new(10., 10.)));
     }
 
+    #[test]
+    fn vec2_resample_degenerate() {
+        let point = Vec2::new(3., 4.);
+
+        assert_eq!(vec![point; 3], Vec2::resample_polyline(&[point, point], 3));
+        assert!(Vec2::<f64>::resample_polyline(&[], 3).is_empty());
+        assert!(Vec2::resample_polyline(&[point], 0).is_empty());
+    }
+
+    #[test]
+    fn vec2_wrap_tiny_negative() {
+        let w = Vec2::new(-1e-20, 0.).wrap(Vec2::new(0., 0.), Vec2::new(10., 10.));
+
+        assert!(w.x() >= 0. && w.x() < 10.);
+        assert_eq!(Vec2::new(0., 0.), w);
+    }
+
+    #[test]
+    fn vec2_try_normalize_underflow() {
+        let mut v = Vec2::new(1e-200, -1e-200);
+
+        assert!(!v.try_normalize_mut());
+        assert_eq!(Vec2::new(1e-200, -1e-200), v);
+    }
+
+    #[test]
+    fn vec2_round_to_many_decimals() {
+        let v = Vec2::new(1.23456, 0.);
+
+        assert_eq!(v, v.round_to(400));
+        assert_eq!(v, v.round_to(u32::MAX));
+        assert_eq!(Vec2::new(1e300, 0.5), Vec2::new(1e300, 0.5).round_to(20));
+    }
+
+    #[test]
+    fn vec2_point_in_degenerate_triangle() {
+        let (a, b, c) = (Vec2::new(0., 0.), Vec2::new(1., 0.), Vec2::new(2., 0.));
+
+        assert!(!Vec2::point_in_triangle(Vec2::new(50., 0.), a, b, c, true));
+        assert!(!Vec2::point_in_triangle(Vec2::new(1., 1.), a, b, c, true));
+        assert!(Vec2::point_in_triangle(Vec2::new(1.5, 0.), a, b, c, true));
+        assert!(!Vec2::point_in_triangle(Vec2::new(1.5, 0.), a, b, c, false));
+
+        let point = Vec2::new(1., 1.);
+        assert!(!Vec2::point_in_triangle(Vec2::new(5., 5.), point, point, point, true));
+        assert!(Vec2::point_in_triangle(point, point, point, point, true));
+    }
+
+    #[test]
+    fn vec2_wrap_stays_in_range() {
+        let (min, max) = (Vec2::new(0.1, 0.1), Vec2::new(0.4, 0.4));
+
+        for k in -1000..1000 {
+            for offset in [-1e-17, 0., 1e-17] {
+                let v = 0.1 + 0.3 * k as f64 + offset;
+                let w = Vec2::new(v, -v).wrap(min, max);
+
+                assert!(w.all(|c| (0.1..0.4).contains(&c)), "{} wrapped to {}", v, w);
+            }
+        }
+    }
+
+    #[test]
+    fn vec2_nearest_skips_nan() {
+        let v = Vec2::new(0., 0.);
+        let nan = f64::NAN;
+
+        assert_eq!(Some(0), v.nearest(&[Vec2::new(1., 0.), Vec2::new(nan, 0.), Vec2::new(50., 0.)]));
+        assert_eq!(Some(1), v.nearest(&[Vec2::new(nan, 0.), Vec2::new(50., 0.), Vec2::new(60., 0.)]));
+        assert_eq!(None, v.nearest(&[Vec2::new(nan, 0.)]));
+    }
+
+    #[test]
+    fn vec2_rotate_by_sincos() {
+        let v = Vec2::new(3., -1.5);
+
+        assert_eq!(Vec2::new(1.5, 3.), v.rotate_by_sincos(1., 0.));
+        assert_eq!(Vec2::new(-3., 1.5), v.rotate_by_sincos(0., -1.));
+        assert_eq!(Vec2::new(-1.5, -3.), v.rotate_by_sincos(-1., 0.));
+
+        // an eighth of a turn, against the rotation matrix written out by hand
+        let h = std::f64::consts::FRAC_1_SQRT_2;
+        let expected = Vec2::new((3. + 1.5) * h, (3. - 1.5) * h);
+
+        assert!(v.rotate_by_sincos(h, h).approx_eq(expected, 1e-12));
+    }
+
+    #[test]
+    fn vec2_convex_hull_degenerate() {
+        let line: Vec<_> = (0..5).map(|i| Vec2::new(i as f64, i as f64 * 2.)).rev().collect();
+        assert_eq!(vec![Vec2::new(0., 0.), Vec2::new(4., 8.)], Vec2::convex_hull(&line));
+
+        let point = Vec2::new(1., 1.);
+        assert_eq!(vec![point], Vec2::convex_hull(&[point, point, point]));
+        assert!(Vec2::<f64>::convex_hull(&[]).is_empty());
+    }
+
+    #[test]
+    fn vec2_convex_hull_edge_points() {
+        let mut rng = lcg(3);
+        let mut points: Vec<_> = (0..50).map(|_| Vec2::new(rng(), rng())).collect();
+        points.extend([
+            Vec2::new(0., 0.),
+            Vec2::new(0.5, 0.),
+            Vec2::new(1., 0.),
+            Vec2::new(1., 1.),
+            Vec2::new(0., 1.),
+        ]);
+
+        assert_eq!(
+            vec![Vec2::new(0., 0.), Vec2::new(1., 0.), Vec2::new(1., 1.), Vec2::new(0., 1.)],
+            Vec2::convex_hull(&points),
+        );
+    }
+
+    #[test]
+    fn vec2_spring_large_dt() {
+        let target = Vec2::new(-4., 7.);
+        let mut position = Vec2::new(0., 0.);
+        let mut velocity = Vec2::new(0., 0.);
+
+        for _ in 0..50 {
+            position = position.spring(target, &mut velocity, 400., 1.);
+            assert!(position.length() <= target.length());
+        }
+
+        assert!(position.approx_eq(target, 1e-9));
+    }
+
+    #[test]
+    fn vec2_hermite_tangents() {
+        let (p0, m0) = (Vec2::new(0., 0.), Vec2::new(3., -1.));
+        let (p1, m1) = (Vec2::new(2., 2.), Vec2::new(-2., 4.));
+        let h = 1e-6;
+
+        let start = (Vec2::hermite(p0, m0, p1, m1, h) - p0) / h;
+        let end = (p1 - Vec2::hermite(p0, m0, p1, m1, 1. - h)) / h;
+
+        assert!(start.approx_eq(m0, 1e-4));
+        assert!(end.approx_eq(m1, 1e-4));
+    }
+
+    #[test]
+    fn vec2_random_in_circle_uniform() {
+        let mut rng = lcg(7);
+        let samples = 20000;
+
+        // a uniform disk has a quarter of its points within half the radius
+        let inner = (0..samples)
+            .map(|_| Vec2::random_in_circle(&mut rng, 2.))
+            .filter(|p| p.length() < 1.)
+            .count();
+
+        assert!((inner as f64 / samples as f64 - 0.25).abs() < 0.02);
+    }
+
     #[test]
     fn vec3_equal() {
         assert_eq!(Vec3::new(5., 5., 5.), Vec3::new(5., 5., 5.));
@@ -82,6 +300,57 @@ mod tests {
         assert_ne!(Vec3::new(5., 6., 7.), Vec3::new(6., 5., 9.));
     }
 
+    #[test]
+    fn vec3_integer() {
+        let v: Vec3<u8> = Vec3::new(1, 2, 3);
+
+        assert_eq!(3, v.fold(0, |max, c| max.max(c)));
+        assert_eq!("(1, 2, 3)", v.to_string());
+    }
+
+    #[test]
+    fn vec3_to_tuple() {
+        let (x, y, z) = Vec3::new(1, 2, 3).to_tuple();
+
+        assert_eq!((1, 2, 3), (x, y, z));
+    }
+
+    #[test]
+    fn vec3_as_ref_slice() {
+        fn sum(values: impl AsRef<[f64]>) -> f64 {
+            values.as_ref().iter().sum()
+        }
+
+        fn zero_first(mut values: impl AsMut<[f64]>) {
+            values.as_mut()[0] = 0.;
+        }
+
+        let mut v = Vec3::new(1., 2., 3.);
+        zero_first(&mut v);
+
+        assert_eq!(5., sum(v));
+        assert_eq!(Vec3::new(0., 2., 3.), v);
+    }
+
+    #[test]
+    fn vec3_debug() {
+        let v = Vec3::new(1., -20.5, 3.);
+
+        assert_eq!("Vec3 { x: 1.0, y: -20.5, z: 3.0 }", format!("{:?}", v));
+        assert_eq!("Vec3 {\n    x:   1.0,\n    y: -20.5,\n    z:   3.0,\n}", format!("{:#?}", v));
+        assert_eq!("Vec3 {\n    x:   1.00,\n    y: -20.50,\n    z:   3.00,\n}", format!("{:#.2?}", v));
+    }
+
+    #[test]
+    fn vec3_btree_key() {
+        let mut map = std::collections::BTreeMap::new();
+        map.insert(Vec3::new(0, 1, 1), "b");
+        map.insert(Vec3::new(0, 1, 0), "a");
+        map.insert(Vec3::new(1, 0, 0), "c");
+
+        assert_eq!(vec!["a", "b", "c"], map.values().copied().collect::<Vec<_>>());
+    }
+
     #[test]
     fn vec3_add() {
         assert_eq!(Vec3::new(5., 5., 5.), Vec3::new(2., 7., 1.) + Vec3::new(3., -2., 4.));
@@ -92,6 +361,27 @@ mod tests {
         assert_eq!(Vec3::new(5., 20., 7.), Vec3::new(10., 30., 10.) - Vec3::new(5., 10., 3.));
     }
 
+    #[test]
+    fn vec3_add_scalar() {
+        assert_eq!(Vec3::new(3., 4., 5.), Vec3::new(1., 2., 3.) + 2.);
+    }
+
+    #[test]
+    fn vec3_sub_scalar() {
+        assert_eq!(Vec3::new(-1., 0., 1.), Vec3::new(1., 2., 3.) - 2.);
+    }
+
+    #[test]
+    fn vec3_scalar_lhs() {
+        let v32 = Vec3::new(1_f32, 2., 4.);
+        let v64 = Vec3::new(1_f64, 2., 4.);
+
+        assert_eq!(v32 * 2., 2. * v32);
+        assert_eq!(v64 * 2., 2. * v64);
+        assert_eq!(Vec3::new(4_f32, 2., 1.), 4. / v32);
+        assert_eq!(Vec3::new(4_f64, 2., 1.), 4. / v64);
+    }
+
     #[test]
     fn vec3_dot() {
         assert_eq!(300., Vec3::new(10., 10., 10.).dot(Vec3::new(10., 10., 10.)));
@@ -102,4 +392,290 @@ mod tests {
         assert_eq!(Vec3::new(10., 51., -42.), Vec3::new(3., 6., 8.).cross(Vec3::new(9., 4., 7.)));
     }
 
+    #[test]
+    fn vec3_random_in_sphere_uniform() {
+        let mut rng = lcg(7);
+        let samples = 20000;
+        let points: Vec<_> = (0..samples).map(|_| Vec3::random_in_sphere(&mut rng, 2.)).collect();
+
+        // a uniform ball has an eighth of its points within half the radius
+        let inner = points.iter().filter(|p| p.length() < 1.).count();
+        assert!((inner as f64 / samples as f64 - 0.125).abs() < 0.02);
+
+        // and no side is favoured
+        let above = points.iter().filter(|p| p.z() > 0.).count();
+        assert!((above as f64 / samples as f64 - 0.5).abs() < 0.02);
+    }
+
+    #[test]
+    fn vec3_distance_large_coordinates() {
+        let a = Vec3::new(1e30_f32, -2e30, 5e29);
+        let b = Vec3::new(-3e30_f32, 1e30, 9e30);
+
+        // the same points worked out in f64, where nothing overflows
+        let wide = |v: Vec3<f32>| Vec3::new(v.x() as f64, v.y() as f64, v.z() as f64);
+        let expected = (wide(b) - wide(a)).length();
+
+        assert_eq!(f32::INFINITY, (b - a).length());
+        assert!((a.distance(b) as f64 / expected - 1.).abs() < 1e-6);
+
+        // small separations at huge magnitudes lose the same precision either way
+        let c = Vec3::new(1e8_f32, 0., 0.);
+        let d = c + Vec3::new(1., 0., 0.);
+
+        assert_eq!((d - c).length(), c.distance(d));
+    }
+
+    #[test]
+    fn vec3_distance_tiny() {
+        let a = Vec3::new(0_f32, 0., 0.);
+        let b = Vec3::new(3e-30_f32, 4e-30, 0.);
+
+        assert_eq!(0., (b - a).length());
+        assert!((a.distance(b) - 5e-30).abs() < 1e-35);
+    }
+
+    #[test]
+    fn vec3_mean_variance_large_offset() {
+        let points: Vec<Vec3<f64>> = [4., 7., 13., 16.]
+            .iter()
+            .map(|&d| Vec3::new(1e9 + d, 1e9 + d, 1e9 + d))
+            .collect();
+
+        let (mean, variance) = Vec3::mean_variance(&points).unwrap();
+
+        assert_eq!(Vec3::new(1e9 + 10., 1e9 + 10., 1e9 + 10.), mean);
+        assert!((variance.x() - 22.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn vec3_rotate_towards_opposite() {
+        let heading = Vec3::new(2_f64, 0., 0.);
+        let turned = heading.rotate_towards(-heading, 0.5);
+
+        assert!((turned.length() - 2.).abs() < 1e-12);
+        assert!((turned.angle_between(heading) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn vec3_nearest_skips_nan() {
+        let v = Vec3::new(0., 0., 0.);
+        let candidates = [Vec3::new(1., 0., 0.), Vec3::new(0., f64::NAN, 0.), Vec3::new(0., 0., 50.)];
+
+        assert_eq!(Some(0), v.nearest(&candidates));
+    }
+
+    #[test]
+    fn vec3_wrap_stays_in_range() {
+        let (min, max) = (Vec3::new(0.1, -2., 0.1), Vec3::new(0.4, 3., 0.4));
+
+        for k in -1000..1000 {
+            for offset in [-1e-17, 0., 1e-17] {
+                let v = 0.1 + 0.3 * k as f64 + offset;
+                let w = Vec3::new(v, v * 7., -v).wrap(min, max);
+
+                assert!((0.1..0.4).contains(&w.x()), "{} wrapped to {}", v, w);
+                assert!((-2.0..3.0).contains(&w.y()), "{} wrapped to {}", v * 7., w);
+                assert!((0.1..0.4).contains(&w.z()), "{} wrapped to {}", -v, w);
+            }
+        }
+    }
+
+    #[test]
+    fn vec3_axis_skips_nan() {
+        let nan = f64::NAN;
+
+        assert_eq!(2, Vec3::new(nan, 1., 2.).max_axis());
+        assert_eq!(1, Vec3::new(nan, 1., 2.).min_axis());
+        assert_eq!(2, Vec3::new(1., nan, 2.).max_axis());
+        assert_eq!(0, Vec3::new(1., 2., nan).min_axis());
+        assert_eq!(0, Vec3::new(nan, nan, nan).max_axis());
+    }
+
+    #[test]
+    fn vec3_dot_slice() {
+        let a = [Vec3::new(1., 2., 3.), Vec3::new(0., 0., 0.), Vec3::new(-1., 4., 2.)];
+        let b = [Vec3::new(4., 5., 6.), Vec3::new(7., 8., 9.), Vec3::new(3., 0.5, -1.)];
+
+        assert_eq!(Some(vec![32., 0., -3.]), Vec3::dot_slice(&a, &b));
+        assert_eq!(Some(vec![]), Vec3::<f64>::dot_slice(&[], &[]));
+    }
+
+    #[test]
+    fn vec3_normalize_slice() {
+        let mut vectors = [
+            Vec3::new(0., 5., 0.),
+            Vec3::new(0., 0., 0.),
+            Vec3::new(1., 1., 1_f64),
+            Vec3::new(1e-200, 0., 0.),
+        ];
+        Vec3::normalize_slice(&mut vectors);
+
+        assert_eq!(Vec3::new(0., 1., 0.), vectors[0]);
+        assert!(vectors[1].is_zero());
+        assert!((vectors[2].length() - 1.).abs() < 1e-12);
+        assert_eq!(Vec3::new(1e-200, 0., 0.), vectors[3]);
+    }
+
+    #[test]
+    fn vec3_try_normalize_non_finite() {
+        let mut tiny = Vec3::new(1e-200, 0., 0.);
+        let mut huge = Vec3::new(f64::INFINITY, 0., 0.);
+
+        assert!(!tiny.try_normalize_mut());
+        assert!(!huge.try_normalize_mut());
+        assert_eq!(Vec3::new(1e-200, 0., 0.), tiny);
+    }
+
+    #[test]
+    fn vec3_normalize_fast() {
+        let mut rng = lcg(11);
+
+        for _ in 0..1000 {
+            let v = Vec3::new(rng() - 0.5, rng() - 0.5, rng() - 0.5) * 1e3;
+            let v = Vec3::new(v.x() as f32, v.y() as f32, v.z() as f32);
+
+            assert!((v.normalize_fast() - v.normalize()).length() < 2e-3);
+        }
+
+        assert_eq!(Vec3::new(0., 0., 0.), Vec3::<f32>::zero().normalize_fast());
+    }
+
+    #[test]
+    fn vec3_to_local_standard_basis() {
+        let (x, y, z) = (Vec3::new(1., 0., 0.), Vec3::new(0., 1., 0.), Vec3::new(0., 0., 1.));
+        let p = Vec3::new(-2.5, 4., 0.75);
+
+        assert_eq!(p, p.to_local(Vec3::zero(), x, y, z));
+        assert_eq!(p - Vec3::new(1., 1., 1.), p.to_local(Vec3::new(1., 1., 1.), x, y, z));
+    }
+
+    #[test]
+    fn vec3_local_round_trip() {
+        let (x, y) = Vec3::new(1., 2., 2_f64).gram_schmidt(Vec3::new(0., 0., 1.));
+        let z = x.cross(y);
+        let origin = Vec3::new(5., -3., 2.);
+        let p = Vec3::new(0.5, 7., -4.);
+
+        let back = p.to_local(origin, x, y, z).from_local(origin, x, y, z);
+
+        assert!(back.approx_eq(p, 1e-12));
+    }
+
+    #[test]
+    fn vec3_lerp_overshoot() {
+        let (a, b) = (Vec3::new(1., 2., 3.), Vec3::new(3., 2., -1.));
+
+        assert_eq!(Vec3::new(4., 2., -3.), a.lerp_unclamped(b, 1.5));
+        assert_eq!(b, a.lerp(b, 1.5));
+        assert_eq!(a.lerp_unclamped(b, 0.25), a.lerp(b, 0.25));
+    }
+
+    #[test]
+    fn vec3_to_padded_array() {
+        let v = Vec3::new(-1.5_f32, 0.25, 8.);
+        let padded = v.to_padded_array();
+
+        assert_eq!(v.as_slice(), &padded[..3]);
+        assert_eq!(0., padded[3]);
+        assert_eq!(16, std::mem::size_of_val(&padded));
+    }
+
+    #[test]
+    fn vec3_yaw_pitch_round_trip() {
+        let v = Vec3::new(-0.3, -0.5, -0.8_f64).normalize();
+        let (yaw, pitch) = v.to_yaw_pitch();
+
+        assert!((Vec3::from_yaw_pitch(yaw, pitch) - v).length() < 1e-12);
+    }
+
+    #[test]
+    fn vec3_clamp_to_cone_behind() {
+        let v = Vec3::new(0.2, -3., 0.1_f64);
+        let axis = Vec3::new(0., 1., 0.);
+        let clamped = v.clamp_to_cone(axis, 0.3);
+
+        assert!((clamped.angle_between_stable(axis) - 0.3).abs() < 1e-12);
+        assert!((clamped.length() - v.length()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn vec3_clamp_to_cone_zero_angle() {
+        let axis = Vec3::new(1., 0., 0.);
+
+        assert_eq!(Vec3::new(5., 0., 0.), Vec3::new(0., 5., 0.).clamp_to_cone(axis, 0.));
+        assert_eq!(Vec3::new(2., 0., 0.), Vec3::new(-2., 0., 0.).clamp_to_cone(axis, -1.));
+    }
+
+    #[test]
+    #[should_panic]
+    fn vec3_with_component_out_of_range() {
+        Vec3::new(1., 2., 3.).with_component(3, 0.);
+    }
+
+    #[test]
+    fn vec3_cross_unsigned() {
+        let v: Vec3<u32> = Vec3::new(1, 0, 0).cross(Vec3::new(0, 1, 0));
+
+        assert_eq!(Vec3::new(0, 0, 1), v);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "subtract with overflow")]
+    fn vec3_cross_unsigned_negative() {
+        // the true result is (0, 0, -1), which a u32 can't hold
+        Vec3::new(0u32, 1, 0).cross(Vec3::new(1, 0, 0));
+    }
+
+    #[test]
+    fn vec3_gram_schmidt_parallel() {
+        let (u, v) = Vec3::new(0., 0., 2_f64).gram_schmidt(Vec3::new(0., 0., -5.));
+
+        assert_eq!(Vec3::new(0., 0., 1.), u);
+        assert!((v.length() - 1.).abs() < 1e-12);
+        assert_eq!(0., u.dot(v));
+    }
+
+    #[test]
+    fn vec3_from_iter() {
+        let v: Vec3<f32> = (1..=3).map(|i| i as f32 * 2.).collect();
+
+        assert_eq!(Vec3::new(2., 4., 6.), v);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vec3_from_iter_too_few() {
+        let _: Vec3<f32> = [1., 2.].into_iter().collect();
+    }
+
+    #[test]
+    fn vec3_aabb_expand() {
+        let points = [
+            Vec3::new(1., 2., 3.),
+            Vec3::new(-1., 5., 0.),
+            Vec3::new(4., -2., 1.),
+            Vec3::new(0., 0., 7.),
+        ];
+        let (mut min, mut max) = (points[0], points[0]);
+        for &p in &points[1..] {
+            Vec3::aabb_expand(&mut min, &mut max, p);
+        }
+
+        assert_eq!(Vec3::new(-1., -2., 0.), min);
+        assert_eq!(Vec3::new(4., 5., 7.), max);
+        assert!(points.iter().all(|&p| Vec3::aabb_contains(min, max, p)));
+    }
+
+    #[test]
+    fn vec3_angle_between_stable_near_opposite() {
+        let v1 = Vec3::new(1., 0., 0_f64);
+        let v2 = Vec3::new(-1., 1e-9, 0.);
+        let expected = std::f64::consts::PI - 1e-9;
+
+        assert!((v1.angle_between_stable(v2) - expected).abs() < 1e-15);
+        assert!((v1.angle_between(v2) - expected).abs() > 1e-10);
+    }
+
 }
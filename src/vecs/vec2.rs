@@ -1,149 +1,2349 @@
-use std::{fmt, ops::{Add, Sub, AddAssign, SubAssign, Mul, Div, Neg}};
-use num_traits::Float;
+use std::{cmp::Ordering, fmt, ops::{Add, Sub, AddAssign, SubAssign, Mul, Div, Neg}};
+use num_traits::{Float, FloatConst, Zero};
 
 /// implementation of a 2D vector
-#[derive(PartialEq, Debug, Clone, Copy)]
-pub struct Vec2<T: Float> {
+#[derive(PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct Vec2<T> {
     x: T,
     y: T
 }
 
-impl<T: Float + Copy> Vec2<T> {
+impl<T: Copy> Vec2<T> {
     /// returns a new Vec2 with the specified coordinates
     /// 
     /// # Examples
-    /// 
+    /// 
+    /// ```
+    /// use vecs::Vec2;
+    /// 
+    /// // creates a new Vec2 called v1
+    /// let v1 = Vec2::new(1.0, 2.0);
+    /// 
+    /// // creates a new Vec2 call v2
+    /// let v2 = Vec2::new(10.0, 20.0);
+    /// ```
+    pub fn new(x: T, y: T) -> Vec2<T> {
+        Vec2 { x, y }
+    }
+
+    /// gets the x value of the Vec2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(15.0, 7.0);
+    ///
+    /// assert_eq!(15.0, v.x());
+    /// ```
+    pub fn x(&self) -> T {
+        self.x
+    }
+
+    /// gets the y value of the Vec2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(15.0, 7.0);
+    ///
+    /// assert_eq!(7.0, v.y());
+    /// ```
+    pub fn y(&self) -> T {
+        self.y
+    }
+
+    /// gets a mutable reference to the x value of the Vec2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let mut v = Vec2::new(1.0, 2.0);
+    ///
+    /// // nudges only the x value
+    /// *v.x_mut() += 1.0;
+    ///
+    /// assert_eq!(Vec2::new(2.0, 2.0), v);
+    /// ```
+    pub fn x_mut(&mut self) -> &mut T {
+        &mut self.x
+    }
+
+    /// gets a mutable reference to the y value of the Vec2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let mut v = Vec2::new(1.0, 2.0);
+    ///
+    /// // nudges only the y value
+    /// *v.y_mut() += 1.0;
+    ///
+    /// assert_eq!(Vec2::new(1.0, 3.0), v);
+    /// ```
+    pub fn y_mut(&mut self) -> &mut T {
+        &mut self.y
+    }
+
+    /// returns an iterator over the components of the Vec2 paired with their
+    /// axis index, yielding (0, x) then (1, y)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(1.0, 2.0);
+    ///
+    /// let pairs: Vec<(usize, f64)> = v.enumerate_components().collect();
+    ///
+    /// assert_eq!(vec![(0, 1.0), (1, 2.0)], pairs);
+    /// ```
+    pub fn enumerate_components(&self) -> impl Iterator<Item = (usize, T)> {
+        [self.x, self.y].into_iter().enumerate()
+    }
+
+    /// sets the x and y values of the Vec2
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecs::Vec2;
+    /// 
+    /// // creates a new Vec2
+    /// let mut v = Vec2::new(9.0, 7.0);
+    /// 
+    /// // gives v new values
+    /// v.set(5.0, 0.0);
+    /// 
+    /// assert_eq!(Vec2::new(5.0, 0.0), v);
+    /// ```
+    pub fn set(&mut self, x: T, y: T) {
+        self.x = x;
+        self.y = y;
+    }
+
+    /// returns the components of the Vec2 as a slice, without copying
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(5.0, 7.0);
+    ///
+    /// assert_eq!(&[5.0, 7.0], v.as_slice());
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: Vec2 is repr(C) and made up of exactly 2 fields of type T,
+        // so it has the same layout as [T; 2]
+        unsafe { &*(self as *const Vec2<T> as *const [T; 2]) }
+    }
+
+    /// returns the components of the Vec2 as a mutable slice, without copying
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let mut v = Vec2::new(5.0, 7.0);
+    ///
+    /// // changes the y value through the slice
+    /// v.as_mut_slice()[1] = 9.0;
+    ///
+    /// assert_eq!(Vec2::new(5.0, 9.0), v);
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: Vec2 is repr(C) and made up of exactly 2 fields of type T,
+        // so it has the same layout as [T; 2]
+        unsafe { &mut *(self as *mut Vec2<T> as *mut [T; 2]) }
+    }
+
+    /// returns a copy of the Vec2 with the component at index `i` replaced,
+    /// where x is index 0 and y is index 1
+    ///
+    /// # Panics
+    ///
+    /// panics if `i` is not 0 or 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(1.0, 2.0);
+    ///
+    /// // replaces the y value
+    /// let w = v.with_component(1, 5.0);
+    ///
+    /// assert_eq!(Vec2::new(1.0, 5.0), w);
+    /// ```
+    pub fn with_component(&self, i: usize, value: T) -> Vec2<T> {
+        match i {
+            0 => Vec2::new(value, self.y),
+            1 => Vec2::new(self.x, value),
+            _ => panic!("component index {} out of range for Vec2", i),
+        }
+    }
+
+    /// returns true if the predicate holds for every component of the Vec2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(-1.0_f64, 2.0);
+    ///
+    /// assert!(v.all(|c| c.is_finite()));
+    /// assert!(!v.all(|c| c > 0.0));
+    /// ```
+    pub fn all<F: Fn(T) -> bool>(&self, f: F) -> bool {
+        f(self.x) && f(self.y)
+    }
+
+    /// returns true if the predicate holds for any component of the Vec2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(-1.0_f64, 2.0);
+    ///
+    /// assert!(v.any(|c| c < 0.0));
+    /// assert!(!v.any(|c| c.is_nan()));
+    /// ```
+    pub fn any<F: Fn(T) -> bool>(&self, f: F) -> bool {
+        f(self.x) || f(self.y)
+    }
+
+    /// folds the components of the Vec2 from left to right, x then y
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(1.0, 2.0);
+    ///
+    /// assert_eq!(v.x() + v.y(), v.fold(0.0, |a, c| a + c));
+    /// assert_eq!(2.0, v.fold(1.0, |a, c| a * c));
+    /// ```
+    pub fn fold<A, F: Fn(A, T) -> A>(&self, init: A, f: F) -> A {
+        f(f(init, self.x), self.y)
+    }
+
+    /// returns the components of the Vec2 as a tuple
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(1, 2);
+    ///
+    /// assert_eq!((1, 2), v.to_tuple());
+    /// ```
+    pub fn to_tuple(&self) -> (T, T) {
+        (self.x, self.y)
+    }
+}
+
+impl<T: Zero + Copy + PartialEq> Vec2<T> {
+    /// returns a Vec2 with every component set to zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates an integer zero vector to accumulate into
+    /// let v: Vec2<i32> = Vec2::zero();
+    ///
+    /// assert_eq!(Vec2::new(0, 0), v);
+    /// ```
+    pub fn zero() -> Vec2<T> {
+        Vec2::new(T::zero(), T::zero())
+    }
+
+    /// returns true if every component of the Vec2 is exactly zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// assert!(Vec2::new(0.0, 0.0).is_zero());
+    /// assert!(!Vec2::new(1e-9, 1e-9).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.x == T::zero() && self.y == T::zero()
+    }
+}
+
+impl<T: Float + Copy> Vec2<T> {
+
+    /// returns a new Vec2 pointing at `angle` radians from the positive x
+    /// axis with the given length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a projectile velocity from a heading and speed
+    /// let v = Vec2::from_angle_length(0.5_f64, 10.0);
+    ///
+    /// assert!((v.length() - 10.0).abs() < 1e-12);
+    /// assert!((v.angle() - 0.5).abs() < 1e-12);
+    /// ```
+    pub fn from_angle_length(angle: T, length: T) -> Vec2<T> {
+        let (sin, cos) = angle.sin_cos();
+
+        Vec2::new(cos * length, sin * length)
+    }
+
+    /// returns the dot product of 2 2D vectors
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecs::Vec2;
+    /// 
+    /// // creates 2 new Vec2 objects
+    /// let v1 = Vec2::new(1.0, 2.0);
+    /// let v2 = Vec2::new(1.0, 2.0);
+    /// 
+    /// // stores their dot product
+    /// let d = v1.dot(v2);
+    /// 
+    /// assert_eq!(5.0, d);
+    /// ```
+    pub fn dot(&self, other: Vec2<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// returns the dot product of 2 2D vectors, taking `other` by reference
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates 2 new Vec2 objects
+    /// let v1 = Vec2::new(1.0, 2.0);
+    /// let v2 = Vec2::new(1.0, 2.0);
+    ///
+    /// // borrows both
+    /// let (r1, r2) = (&v1, &v2);
+    ///
+    /// assert_eq!(5.0, r1.dot_ref(r2));
+    /// ```
+    pub fn dot_ref(&self, other: &Vec2<T>) -> T {
+        self.dot(*other)
+    }
+
+    /// returns the length of the Vec2
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecs::Vec2;
+    /// 
+    /// // creates a new Vec2
+    /// let v = Vec2::new(10.0, 10.0);
+    /// 
+    /// // gets its length
+    /// let len = v.length();
+    /// 
+    /// assert_eq!(f64::sqrt(200.0), len);
+    /// ```
+    pub fn length(&self) -> T {
+        (self.x.powi(2) + self.y.powi(2)).sqrt()
+    }
+
+    /// returns the squared length of the Vec2
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecs::Vec2;
+    /// 
+    /// // creates a new Vec2
+    /// let v = Vec2::new(10.0, 10.0);
+    /// 
+    /// // gets its length
+    /// let len = v.length_squared();
+    /// 
+    /// assert_eq!(200.0, len);
+    /// ```
+    pub fn length_squared(&self) -> T {
+        self.x.powi(2) + self.y.powi(2)
+    }
+
+    /// returns the reciprocal of the length of the Vec2, `1 / length`
+    ///
+    /// a zero length Vec2 gives positive infinity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(0.0, 2.0);
+    ///
+    /// assert_eq!(0.5, v.inv_length());
+    /// assert_eq!(f64::INFINITY, Vec2::new(0.0, 0.0).inv_length());
+    /// ```
+    pub fn inv_length(&self) -> T {
+        self.length().recip()
+    }
+
+    /// returns the normal of the Vec2 in (-y, x) format
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecs::Vec2;
+    /// 
+    /// // creates a new Vec2
+    /// let v = Vec2::new(4.0, 9.0);
+    /// 
+    /// // stores it's normal
+    /// let normal = v.normal();
+    /// 
+    /// assert_eq!(Vec2::new(-9.0, 4.0), normal);
+    /// ```
+    pub fn normal(&self) -> Vec2<T> {
+        Vec2::new(-self.y, self.x)
+    }
+
+    /// returns the counter-clockwise perpendicular of the Vec2, (-y, x)
+    ///
+    /// this is the same as `normal()`, which it calls
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(1.0, 0.0);
+    ///
+    /// // stores it's left hand perpendicular
+    /// let p = v.perp_ccw();
+    ///
+    /// assert_eq!(Vec2::new(0.0, 1.0), p);
+    /// assert_eq!(v.normal(), p);
+    /// ```
+    pub fn perp_ccw(&self) -> Vec2<T> {
+        self.normal()
+    }
+
+    /// returns the clockwise perpendicular of the Vec2, (y, -x)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(1.0, 0.0);
+    ///
+    /// // stores it's right hand perpendicular
+    /// let p = v.perp_cw();
+    ///
+    /// assert_eq!(Vec2::new(0.0, -1.0), p);
+    /// ```
+    pub fn perp_cw(&self) -> Vec2<T> {
+        Vec2::new(self.y, -self.x)
+    }
+
+    /// returns the normalized the Vec2
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecs::Vec2;
+    /// 
+    /// // creates a new Vec3
+    /// let v = Vec2::new(100.0, 0.0);
+    /// 
+    /// // stores the normalized Vec3
+    /// let n = v.normalize();
+    /// 
+    /// assert_eq!(Vec2::new(1.0, 0.0), n);
+    /// ```
+    pub fn normalize(&self) -> Vec2<T> {
+        let length = self.length();
+
+        *self / length
+    }
+    
+    /// normalizes the Vec2 in place
+    ///
+    /// a zero length Vec2 has no direction, so it becomes NaN. use
+    /// `try_normalize_mut` to leave it unchanged instead
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let mut v = Vec2::new(3.0_f64, 4.0);
+    ///
+    /// v.normalize_mut();
+    ///
+    /// assert!((v.length() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn normalize_mut(&mut self) {
+        *self = self.normalize();
+    }
+
+    /// normalizes the Vec2 in place, returning false and leaving it unchanged
+    /// if its computed length is zero or not finite
+    ///
+    /// this covers vectors so short that their length underflows to zero,
+    /// which would otherwise be divided into infinities and NaNs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a zero length Vec2
+    /// let mut v = Vec2::new(0.0, 0.0);
+    ///
+    /// assert!(!v.try_normalize_mut());
+    /// assert_eq!(Vec2::new(0.0, 0.0), v);
+    /// ```
+    pub fn try_normalize_mut(&mut self) -> bool {
+        let length = self.length();
+
+        if length == T::zero() || !length.is_finite() {
+            return false;
+        }
+
+        *self = *self / length;
+
+        true
+    }
+
+    /// returns the absolute version of the Vec2
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecs::Vec2;
+    /// 
+    /// // creates a new Vec2
+    /// let v = Vec2::new(-12.0, 15.0);
+    /// 
+    /// // stores it's absolute variant
+    /// let a = v.abs();
+    /// 
+    /// assert_eq!(Vec2::new(12.0, 15.0), a);
+    /// ```
+    pub fn abs(&self) -> Vec2<T> {
+        Vec2::new(self.x.abs(), self.y.abs())
+    }
+
+    /// returns the sign of each component of the Vec2 as -1, 0 or 1
+    ///
+    /// unlike `signum`, components that are exactly zero stay zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a Vec2 with a zero component
+    /// let v = Vec2::new(-3.5, 0.0);
+    ///
+    /// assert_eq!(Vec2::new(-1.0, 0.0), v.sign_or_zero());
+    /// ```
+    pub fn sign_or_zero(&self) -> Vec2<T> {
+        let sign = |c: T| if c == T::zero() { c } else { c.signum() };
+
+        Vec2::new(sign(self.x), sign(self.y))
+    }
+
+    /// returns the Vec2 scaled down to a length of `max` if it is longer,
+    /// otherwise returns it unchanged
+    ///
+    /// this is the same operation as clamping the length to a maximum,
+    /// named after the `limit` used in steering behaviours
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates 2 new Vec2 objects
+    /// let fast = Vec2::new(30.0, 40.0);
+    /// let slow = Vec2::new(3.0, 4.0);
+    ///
+    /// // limits both to a length of 10
+    /// assert_eq!(Vec2::new(6.0, 8.0), fast.limit(10.0));
+    /// assert_eq!(Vec2::new(3.0, 4.0), slow.limit(10.0));
+    /// ```
+    pub fn limit(&self, max: T) -> Vec2<T> {
+        let length_squared = self.length_squared();
+
+        if length_squared <= max * max {
+            *self
+        } else {
+            *self * (max / length_squared.sqrt())
+        }
+    }
+
+    /// returns the Vec2 with each component wrapped into the range
+    /// [min, max), so values that leave one side come back in on the other
+    ///
+    /// wrapping uses a floored modulo, so negative coordinates wrap
+    /// correctly as well
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a position that has left the right edge of the world
+    /// let v = Vec2::new(12.0, -1.0);
+    ///
+    /// // wraps it back into a 10x10 world
+    /// let w = v.wrap(Vec2::new(0.0, 0.0), Vec2::new(10.0, 10.0));
+    ///
+    /// assert_eq!(Vec2::new(2.0, 9.0), w);
+    /// ```
+    pub fn wrap(&self, min: Vec2<T>, max: Vec2<T>) -> Vec2<T> {
+        let wrap = |v: T, min: T, max: T| {
+            let range = max - min;
+            let offset = v - min;
+
+            let wrapped = min + offset - range * (offset / range).floor();
+
+            // rounding can push the result just outside the range, such as a
+            // tiny negative offset landing exactly on max
+            if wrapped < min || wrapped >= max {
+                min
+            } else {
+                wrapped
+            }
+        };
+
+        Vec2::new(wrap(self.x, min.x, max.x), wrap(self.y, min.y, max.y))
+    }
+
+    /// returns the Vec2 rotated exactly 90 degrees counter-clockwise
+    ///
+    /// only swaps and negates components, so unlike a trig based
+    /// rotation no floating point error is introduced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(0.1, 0.7);
+    ///
+    /// // four quarter turns bring it back exactly
+    /// let r = v.rotate_90_ccw().rotate_90_ccw().rotate_90_ccw().rotate_90_ccw();
+    ///
+    /// assert_eq!(Vec2::new(-0.7, 0.1), v.rotate_90_ccw());
+    /// assert_eq!(v, r);
+    /// ```
+    pub fn rotate_90_ccw(&self) -> Vec2<T> {
+        Vec2::new(-self.y, self.x)
+    }
+
+    /// returns the Vec2 rotated exactly 90 degrees clockwise
+    ///
+    /// only swaps and negates components, so unlike a trig based
+    /// rotation no floating point error is introduced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(0.1, 0.7);
+    ///
+    /// // four quarter turns bring it back exactly
+    /// let r = v.rotate_90_cw().rotate_90_cw().rotate_90_cw().rotate_90_cw();
+    ///
+    /// assert_eq!(Vec2::new(0.7, -0.1), v.rotate_90_cw());
+    /// assert_eq!(v, r);
+    /// ```
+    pub fn rotate_90_cw(&self) -> Vec2<T> {
+        Vec2::new(self.y, -self.x)
+    }
+
+    /// returns the shortest signed difference from one heading to another,
+    /// in radians within (-π, π]
+    ///
+    /// the raw difference is wrapped around, so interpolating by the result
+    /// never turns the long way around the circle
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // the raw difference would be -6 radians
+    /// let d = Vec2::angle_difference(3.0, -3.0);
+    ///
+    /// assert!((d - (2.0 * std::f64::consts::PI - 6.0)).abs() < 1e-12);
+    /// ```
+    pub fn angle_difference(from: T, to: T) -> T where T: FloatConst {
+        let tau = T::PI() + T::PI();
+        let diff = to - from;
+
+        diff - tau * ((diff - T::PI()) / tau).ceil()
+    }
+
+    /// returns the Vec2 transformed by a 2x2 matrix
+    ///
+    /// the matrix is row-major, so `matrix[0]` is the first row and the
+    /// result is the matrix-vector product `matrix * self`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(2.0, 3.0);
+    ///
+    /// // the identity matrix leaves it unchanged
+    /// assert_eq!(v, v.transform([[1.0, 0.0], [0.0, 1.0]]));
+    ///
+    /// // a 90 degree counter-clockwise rotation matrix
+    /// assert_eq!(Vec2::new(-3.0, 2.0), v.transform([[0.0, -1.0], [1.0, 0.0]]));
+    /// ```
+    pub fn transform(&self, matrix: [[T; 2]; 2]) -> Vec2<T> {
+        Vec2::new(
+            matrix[0][0] * self.x + matrix[0][1] * self.y,
+            matrix[1][0] * self.x + matrix[1][1] * self.y,
+        )
+    }
+
+    /// returns the absolute difference between each component of 2 Vec2s
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates 2 new Vec2 objects
+    /// let v1 = Vec2::new(1.0, 5.0);
+    /// let v2 = Vec2::new(4.0, 2.0);
+    ///
+    /// // stores the per axis difference
+    /// let d = v1.abs_diff(v2);
+    ///
+    /// assert_eq!(Vec2::new(3.0, 3.0), d);
+    /// ```
+    pub fn abs_diff(&self, other: Vec2<T>) -> Vec2<T> {
+        (*self - other).abs()
+    }
+
+    /// returns true if the length of the Vec2 is within `epsilon` of zero
+    ///
+    /// useful for guarding against the NaN produced by normalizing a zero
+    /// length vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a tiny Vec2
+    /// let v = Vec2::new(1e-9, 1e-9);
+    ///
+    /// assert!(v.approx_zero(1e-6));
+    /// assert!(!v.approx_zero(1e-12));
+    /// ```
+    pub fn approx_zero(&self, epsilon: T) -> bool {
+        self.length_squared() <= epsilon * epsilon
+    }
+
+    /// returns the total length of a path through the given points,
+    /// summing the distances between each consecutive pair
+    ///
+    /// an empty or single point slice has a length of zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a path of 3 points spaced 1 apart
+    /// let path = [Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(2.0, 0.0)];
+    ///
+    /// assert_eq!(2.0, Vec2::polyline_length(&path));
+    /// assert_eq!(0.0, Vec2::polyline_length(&[Vec2::new(4.0, 2.0)]));
+    /// ```
+    pub fn polyline_length(points: &[Vec2<T>]) -> T {
+        points
+            .windows(2)
+            .fold(T::zero(), |total, pair| total + (pair[1] - pair[0]).length())
+    }
+
+    /// returns `count` points spaced evenly by arc length along the path
+    /// through the given points, from its first point to its last
+    ///
+    /// an empty path or a `count` of zero gives no points, and a path with
+    /// no length gives `count` copies of its first point
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates an unevenly spaced straight path
+    /// let path = [Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(4.0, 0.0)];
+    ///
+    /// // resamples it into 5 evenly spaced points
+    /// let samples = Vec2::resample_polyline(&path, 5);
+    ///
+    /// assert_eq!(vec![
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(1.0, 0.0),
+    ///     Vec2::new(2.0, 0.0),
+    ///     Vec2::new(3.0, 0.0),
+    ///     Vec2::new(4.0, 0.0),
+    /// ], samples);
+    /// ```
+    pub fn resample_polyline(points: &[Vec2<T>], count: usize) -> Vec<Vec2<T>> {
+        let (first, last) = match (points.first(), points.last()) {
+            (Some(first), Some(last)) if count > 0 => (*first, *last),
+            _ => return Vec::new(),
+        };
+
+        let total = Vec2::polyline_length(points);
+
+        if count == 1 || total <= T::zero() {
+            return vec![first; count];
+        }
+
+        let step = total / T::from(count - 1).unwrap();
+        let mut samples = Vec::with_capacity(count);
+
+        // index of the current segment and the distance to its start
+        let mut segment = 0;
+        let mut travelled = T::zero();
+
+        for i in 0..count - 1 {
+            let target = step * T::from(i).unwrap();
+
+            while segment < points.len() - 2
+                && travelled + (points[segment + 1] - points[segment]).length() < target
+            {
+                travelled = travelled + (points[segment + 1] - points[segment]).length();
+                segment += 1;
+            }
+
+            let start = points[segment];
+            let delta = points[segment + 1] - start;
+            let length = delta.length();
+
+            let t = if length > T::zero() {
+                ((target - travelled) / length).min(T::one())
+            } else {
+                T::zero()
+            };
+
+            samples.push(start + delta * t);
+        }
+
+        // the end point is pushed directly so rounding can't move it
+        samples.push(last);
+
+        samples
+    }
+
+    /// returns the Vec2 with `e` raised to the power of each component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(0.5_f64, 3.0);
+    ///
+    /// // takes it out of log space and back again
+    /// let r = v.exp().ln();
+    ///
+    /// assert!((r - v).all(|c| c.abs() < 1e-12));
+    /// ```
+    pub fn exp(&self) -> Vec2<T> {
+        Vec2::new(self.x.exp(), self.y.exp())
+    }
+
+    /// returns the Vec2 with the natural logarithm of each component
+    ///
+    /// a zero component gives negative infinity and a negative one gives NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(0.5_f64, 3.0);
+    ///
+    /// // takes it into log space and back again
+    /// let r = v.ln().exp();
+    ///
+    /// assert!((r - v).all(|c| c.abs() < 1e-12));
+    /// ```
+    pub fn ln(&self) -> Vec2<T> {
+        Vec2::new(self.x.ln(), self.y.ln())
+    }
+
+    /// returns the Vec2 with the sine of each component, in radians
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(0.0, FRAC_PI_2);
+    ///
+    /// // takes the sine of each component
+    /// let s = v.sin();
+    ///
+    /// assert!((s - Vec2::new(0.0, 1.0)).all(|c| c.abs() < 1e-12));
+    /// ```
+    pub fn sin(&self) -> Vec2<T> {
+        Vec2::new(self.x.sin(), self.y.sin())
+    }
+
+    /// returns the Vec2 with the cosine of each component, in radians
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(0.0, FRAC_PI_2);
+    ///
+    /// // takes the cosine of each component
+    /// let c = v.cos();
+    ///
+    /// assert!((c - Vec2::new(1.0, 0.0)).all(|c| c.abs() < 1e-12));
+    /// ```
+    pub fn cos(&self) -> Vec2<T> {
+        Vec2::new(self.x.cos(), self.y.cos())
+    }
+
+    /// returns the dot product of 2 2D vectors, accumulated with a fused
+    /// multiply-add to reduce the rounding error of the sum
+    ///
+    /// the y product is still rounded before it is added, so the result is
+    /// only exact when that product is
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // the exact dot product of these is 2^-60
+    /// let a = 1.0 + 2f64.powi(-30);
+    /// let v1 = Vec2::new(a, 1.0);
+    /// let v2 = Vec2::new(a, -(1.0 + 2f64.powi(-29)));
+    ///
+    /// // the naive dot product rounds it away entirely, while the y
+    /// // product here is exact so only the fused step rounds
+    /// assert_eq!(0.0, v1.dot(v2));
+    /// assert_eq!(2f64.powi(-60), v1.dot_fma(v2));
+    /// ```
+    pub fn dot_fma(&self, other: Vec2<T>) -> T {
+        self.x.mul_add(other.x, self.y * other.y)
+    }
+
+    /// returns the angle of the Vec2 from the positive x axis, in radians
+    /// within [-π, π]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// // creates a new Vec2 pointing straight up
+    /// let v = Vec2::new(0.0, 3.0);
+    ///
+    /// assert_eq!(FRAC_PI_2, v.angle());
+    /// ```
+    pub fn angle(&self) -> T {
+        self.y.atan2(self.x)
+    }
+
+    /// returns the heading from the point toward `other`, in radians from
+    /// the positive x axis
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// // creates a turret and a target straight above it
+    /// let turret = Vec2::new(0.0, 0.0);
+    /// let target = Vec2::new(0.0, 1.0);
+    ///
+    /// assert_eq!(FRAC_PI_2, turret.angle_to(target));
+    /// ```
+    pub fn angle_to(&self, other: Vec2<T>) -> T {
+        (other - *self).angle()
+    }
+
+    /// returns the signed angle from `reference` to the Vec2, in radians
+    /// within (-π, π], positive when the Vec2 is counter-clockwise of it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    /// use std::f64::consts::{FRAC_PI_2, PI};
+    ///
+    /// // measures angles from straight up
+    /// let up = Vec2::new(0.0, 1.0);
+    ///
+    /// assert_eq!(FRAC_PI_2, Vec2::new(-1.0, 0.0).angle_from(up));
+    /// assert_eq!(-FRAC_PI_2, Vec2::new(1.0, 0.0).angle_from(up));
+    /// assert_eq!(0.0, Vec2::new(0.0, 3.0).angle_from(up));
+    ///
+    /// // opposite directions are a half turn counter-clockwise
+    /// assert_eq!(PI, Vec2::new(0.0, -1.0).angle_from(up));
+    /// ```
+    pub fn angle_from(&self, reference: Vec2<T>) -> T where T: FloatConst {
+        let angle = reference.perp_dot(*self).atan2(reference.dot(*self));
+
+        // a perp dot of -0.0 puts opposite directions at -π
+        if angle <= -T::PI() {
+            T::PI()
+        } else {
+            angle
+        }
+    }
+
+    /// returns the angle of the Vec2 from the positive x axis, in degrees
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2 pointing straight up
+    /// let v = Vec2::new(0.0, 3.0);
+    ///
+    /// assert_eq!(90.0, v.angle_degrees());
+    /// ```
+    pub fn angle_degrees(&self) -> T {
+        self.angle().to_degrees()
+    }
+
+    /// returns the unsigned angle between 2 2D vectors, in radians
+    /// within [0, π]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// // creates 2 new Vec2 objects
+    /// let v1 = Vec2::new(2.0, 0.0);
+    /// let v2 = Vec2::new(0.0, -5.0);
+    ///
+    /// assert_eq!(FRAC_PI_2, v1.angle_between(v2));
+    /// ```
+    pub fn angle_between(&self, other: Vec2<T>) -> T {
+        let cos = self.dot(other) / (self.length() * other.length());
+
+        cos.max(-T::one()).min(T::one()).acos()
+    }
+
+    /// returns the unsigned angle between 2 2D vectors, in degrees
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates 2 new Vec2 objects
+    /// let v1 = Vec2::new(2.0, 0.0);
+    /// let v2 = Vec2::new(0.0, -5.0);
+    ///
+    /// assert_eq!(90.0, v1.angle_between_degrees(v2));
+    /// ```
+    pub fn angle_between_degrees(&self, other: Vec2<T>) -> T {
+        self.angle_between(other).to_degrees()
+    }
+
+    /// returns the perp dot product of 2 2D vectors, the z component of
+    /// their 3D cross product
+    ///
+    /// the result is positive when `other` is counter-clockwise from `self`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates 2 new Vec2 objects
+    /// let v1 = Vec2::new(1.0, 0.0);
+    /// let v2 = Vec2::new(0.0, 2.0);
+    ///
+    /// assert_eq!(2.0, v1.perp_dot(v2));
+    /// assert_eq!(-2.0, v2.perp_dot(v1));
+    /// ```
+    pub fn perp_dot(&self, other: Vec2<T>) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// returns the Vec2 rotated counter-clockwise by `angle` radians
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    /// use std::f64::consts::PI;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(1.0, 0.0);
+    ///
+    /// // rotates it a half turn
+    /// let r = v.rotate(PI);
+    ///
+    /// assert!((r - Vec2::new(-1.0, 0.0)).all(|c| c.abs() < 1e-12));
+    /// ```
+    pub fn rotate(&self, angle: T) -> Vec2<T> {
+        let (sin, cos) = angle.sin_cos();
+
+        self.rotate_by_sincos(sin, cos)
+    }
+
+    /// returns the Vec2 rotated counter-clockwise by the angle with the given
+    /// sine and cosine
+    ///
+    /// this saves recomputing them when rotating many Vec2s by the same angle
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // works out the sine and cosine of a quarter turn once
+    /// let (sin, cos) = (1.0, 0.0);
+    ///
+    /// let points = [Vec2::new(1.0, 0.0), Vec2::new(0.0, 2.0)];
+    /// let rotated: Vec<_> = points.iter().map(|p| p.rotate_by_sincos(sin, cos)).collect();
+    ///
+    /// assert_eq!(vec![Vec2::new(0.0, 1.0), Vec2::new(-2.0, 0.0)], rotated);
+    /// ```
+    pub fn rotate_by_sincos(&self, sin: T, cos: T) -> Vec2<T> {
+        Vec2::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
+    /// returns the Vec2 rotated toward `target` by at most `max_radians`
+    ///
+    /// if `target` is within `max_radians` it is returned as is, otherwise
+    /// the Vec2 is rotated by `max_radians` the short way around, keeping
+    /// its length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// // creates a heading and a target a quarter turn away
+    /// let heading = Vec2::new(1.0, 0.0);
+    /// let target = Vec2::new(0.0, 1.0);
+    ///
+    /// // still turning, so only half of the way there
+    /// let turned = heading.rotate_towards(target, FRAC_PI_2 / 2.0);
+    /// assert!((turned.angle() - FRAC_PI_2 / 2.0).abs() < 1e-12);
+    ///
+    /// // close enough to arrive
+    /// assert_eq!(target, heading.rotate_towards(target, FRAC_PI_2));
+    /// ```
+    pub fn rotate_towards(&self, target: Vec2<T>, max_radians: T) -> Vec2<T> {
+        let angle = self.perp_dot(target).atan2(self.dot(target));
+
+        if angle.abs() <= max_radians {
+            target
+        } else {
+            self.rotate(max_radians * angle.signum())
+        }
+    }
+
+    /// returns true if the point `p` lies inside the triangle `a`, `b`, `c`
+    ///
+    /// works with either winding order. when `inclusive` is true points
+    /// exactly on an edge or corner count as inside, otherwise they don't
+    ///
+    /// a degenerate triangle with no area has no inside, so only the
+    /// inclusive test can pass, for points on the segment or single point
+    /// its corners collapse onto
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a triangle
+    /// let a = Vec2::new(0.0, 0.0);
+    /// let b = Vec2::new(4.0, 0.0);
+    /// let c = Vec2::new(0.0, 4.0);
+    ///
+    /// assert!(Vec2::point_in_triangle(Vec2::new(1.0, 1.0), a, b, c, false));
+    /// assert!(Vec2::point_in_triangle(Vec2::new(1.0, 1.0), c, b, a, false));
+    /// assert!(!Vec2::point_in_triangle(Vec2::new(3.0, 3.0), a, b, c, true));
+    ///
+    /// // a point on the edge from a to b
+    /// assert!(Vec2::point_in_triangle(Vec2::new(2.0, 0.0), a, b, c, true));
+    /// assert!(!Vec2::point_in_triangle(Vec2::new(2.0, 0.0), a, b, c, false));
+    ///
+    /// // a flat triangle only covers the segment along its corners
+    /// let flat = Vec2::new(8.0, 0.0);
+    /// assert!(Vec2::point_in_triangle(Vec2::new(6.0, 0.0), a, b, flat, true));
+    /// assert!(!Vec2::point_in_triangle(Vec2::new(9.0, 0.0), a, b, flat, true));
+    /// ```
+    pub fn point_in_triangle(p: Vec2<T>, a: Vec2<T>, b: Vec2<T>, c: Vec2<T>, inclusive: bool) -> bool {
+        let d1 = (b - a).perp_dot(p - a);
+        let d2 = (c - b).perp_dot(p - b);
+        let d3 = (a - c).perp_dot(p - c);
+
+        let zero = T::zero();
+
+        // without this every edge test is zero and any point would pass
+        if inclusive && (b - a).perp_dot(c - a) == zero {
+            let (mut min, mut max) = (a, a);
+            Vec2::aabb_expand(&mut min, &mut max, b);
+            Vec2::aabb_expand(&mut min, &mut max, c);
+
+            return d1 == zero && d2 == zero && d3 == zero && Vec2::aabb_contains(min, max, p);
+        }
+
+        if inclusive {
+            let has_negative = d1 < zero || d2 < zero || d3 < zero;
+            let has_positive = d1 > zero || d2 > zero || d3 > zero;
+
+            !(has_negative && has_positive)
+        } else {
+            (d1 > zero && d2 > zero && d3 > zero) || (d1 < zero && d2 < zero && d3 < zero)
+        }
+    }
+
+    /// returns the Vec2 as a complex number in (real, imaginary) form
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(3.0, 4.0);
+    ///
+    /// assert_eq!((3.0, 4.0), v.to_complex());
+    /// ```
+    pub fn to_complex(&self) -> (T, T) {
+        (self.x, self.y)
+    }
+
+    /// returns the Vec2 multiplied as a complex number by `re + im * i`,
+    /// which rotates it by the angle of that number and scales it by its
+    /// magnitude
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(3.0, 4.0);
+    ///
+    /// // multiplying by i is a 90 degree rotation
+    /// assert_eq!(Vec2::new(-4.0, 3.0), v.rotate_by_complex(0.0, 1.0));
+    /// ```
+    pub fn rotate_by_complex(&self, re: T, im: T) -> Vec2<T> {
+        Vec2::new(self.x * re - self.y * im, self.x * im + self.y * re)
+    }
+
+    /// returns the point mirrored across the line through the origin that
+    /// points along `line_dir`
+    ///
+    /// `line_dir` doesn't need to be normalized. a zero length direction
+    /// doesn't define a line, so the point is returned unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(1.0, 1.0);
+    ///
+    /// // mirrors it across the x axis
+    /// assert_eq!(Vec2::new(1.0, -1.0), v.mirror_across(Vec2::new(3.0, 0.0)));
+    /// assert_eq!(v, v.mirror_across(Vec2::new(0.0, 0.0)));
+    /// ```
+    pub fn mirror_across(&self, line_dir: Vec2<T>) -> Vec2<T> {
+        let length_squared = line_dir.length_squared();
+
+        if length_squared == T::zero() {
+            return *self;
+        }
+
+        let projection = line_dir * (self.dot(line_dir) / length_squared);
+
+        projection + projection - *self
+    }
+
+    /// returns the point scaled component-wise by `factor` around `pivot`
+    /// instead of the origin
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a point and a pivot to scale it around
+    /// let v = Vec2::new(3.0, 3.0);
+    /// let pivot = Vec2::new(1.0, 1.0);
+    ///
+    /// let s = v.scale_around(pivot, Vec2::new(2.0, 2.0));
+    ///
+    /// assert_eq!(Vec2::new(5.0, 5.0), s);
+    /// ```
+    pub fn scale_around(&self, pivot: Vec2<T>, factor: Vec2<T>) -> Vec2<T> {
+        (*self - pivot) * factor + pivot
+    }
+
+    /// returns true if the 3 points lie on a line, within a tolerance
+    ///
+    /// the points count as collinear when the area of the triangle they
+    /// form is at most `epsilon`, since floats are almost never exactly
+    /// collinear
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates 3 points on a line
+    /// let a = Vec2::new(0.0, 0.0);
+    /// let b = Vec2::new(1.0, 1.0);
+    /// let c = Vec2::new(2.0, 2.0);
+    ///
+    /// assert!(Vec2::are_collinear(a, b, c, 1e-9));
+    ///
+    /// // nudges the last point slightly off the line
+    /// let c = Vec2::new(2.0, 2.001);
+    ///
+    /// assert!(Vec2::are_collinear(a, b, c, 1e-3));
+    /// assert!(!Vec2::are_collinear(a, b, c, 1e-6));
+    /// ```
+    pub fn are_collinear(a: Vec2<T>, b: Vec2<T>, c: Vec2<T>, epsilon: T) -> bool {
+        let area = (b - a).perp_dot(c - a) / (T::one() + T::one());
+
+        area.abs() <= epsilon
+    }
+
+    /// returns how many components of the Vec2 are not zero
+    ///
+    /// NaN components are not equal to zero, so they are counted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(0.0, 2.0);
+    ///
+    /// assert_eq!(1, v.count_nonzero());
+    /// ```
+    pub fn count_nonzero(&self) -> usize {
+        self.fold(0, |count, c| if c != T::zero() { count + 1 } else { count })
+    }
+
+    /// returns how many components of the Vec2 are NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a Vec2 that has blown up
+    /// let v = Vec2::new(f64::NAN, 2.0);
+    ///
+    /// assert_eq!(1, v.count_nan());
+    /// ```
+    pub fn count_nan(&self) -> usize {
+        self.fold(0, |count, c| if c.is_nan() { count + 1 } else { count })
+    }
+
+    /// returns the Vec2 with each component rounded to `decimals` decimal
+    /// places
+    ///
+    /// each component is scaled by 10^decimals before rounding, and any
+    /// component whose scaled value overflows to infinity is returned as it
+    /// is, which includes every component once 10^decimals itself overflows
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a noisy Vec2
+    /// let v = Vec2::new(1.23456, 2.0);
+    ///
+    /// assert_eq!(Vec2::new(1.23, 2.0), v.round_to(2));
+    /// ```
+    pub fn round_to(&self, decimals: u32) -> Vec2<T> {
+        let exponent = i32::try_from(decimals).unwrap_or(i32::MAX);
+        let factor = T::from(10).unwrap().powi(exponent);
+
+        let round = |c: T| {
+            let scaled = c * factor;
+
+            if scaled.is_finite() {
+                scaled.round() / factor
+            } else {
+                c
+            }
+        };
+
+        Vec2::new(round(self.x), round(self.y))
+    }
+
+    /// returns true if every component of the 2 Vec2s is within `epsilon`
+    /// of the other
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates 2 nearly equal Vec2 objects
+    /// let v1 = Vec2::new(1.0, 2.0);
+    /// let v2 = Vec2::new(1.0 + 1e-7, 2.0);
+    ///
+    /// assert!(v1.approx_eq(v2, 1e-6));
+    /// assert!(!v1.approx_eq(v2, 1e-8));
+    /// ```
+    pub fn approx_eq(&self, other: Vec2<T>, epsilon: T) -> bool {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
+
+    /// returns the rejection of the Vec2 from `other`, the part of it
+    /// perpendicular to `other`, along with that part's length
+    ///
+    /// a zero length `other` gives NaN components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates 2 new Vec2 objects
+    /// let v = Vec2::new(3.0, 4.0);
+    /// let axis = Vec2::new(2.0, 0.0);
+    ///
+    /// let (rejection, length) = v.reject_with_magnitude(axis);
+    ///
+    /// assert_eq!(Vec2::new(0.0, 4.0), rejection);
+    /// assert_eq!(4.0, length);
+    /// ```
+    pub fn reject_with_magnitude(&self, other: Vec2<T>) -> (Vec2<T>, T) {
+        let rejection = *self - other * (self.dot(other) / other.length_squared());
+
+        (rejection, rejection.length())
+    }
+
+    /// returns the position after one step of a critically damped spring
+    /// pulling the Vec2 toward `target`, updating `velocity` in place
+    ///
+    /// the damping is derived from `stiffness` so the spring settles as fast
+    /// as possible without oscillating. the step is implicit, so it stays
+    /// stable even for large `dt` where an explicit Euler spring explodes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // starts at rest away from the target
+    /// let target = Vec2::new(10.0, 0.0);
+    /// let mut position = Vec2::new(0.0, 0.0);
+    /// let mut velocity = Vec2::new(0.0, 0.0);
+    ///
+    /// for _ in 0..100 {
+    ///     position = position.spring(target, &mut velocity, 100.0, 0.1);
+    ///
+    ///     // never overshoots the target
+    ///     assert!(position.x() <= 10.0);
+    /// }
+    ///
+    /// assert!(position.approx_eq(target, 1e-9));
+    /// ```
+    pub fn spring(&self, target: Vec2<T>, velocity: &mut Vec2<T>, stiffness: T, dt: T) -> Vec2<T> {
+        let damping = (T::one() + T::one()) * stiffness.sqrt();
+        let denominator = T::one() + dt * damping + dt * dt * stiffness;
+
+        *velocity = (*velocity - (*self - target) * (dt * stiffness)) / denominator;
+
+        *self + *velocity * dt
+    }
+
+    /// returns the index of the candidate closest to the Vec2, or `None` if
+    /// there are no candidates
+    ///
+    /// compares squared distances, so no square roots are taken. ties go to
+    /// the earliest candidate, and candidates at a NaN distance are skipped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a point and some nodes to snap to
+    /// let v = Vec2::new(2.0, 2.0);
+    /// let nodes = [Vec2::new(0.0, 0.0), Vec2::new(3.0, 1.0), Vec2::new(5.0, 5.0)];
+    ///
+    /// assert_eq!(Some(1), v.nearest(&nodes));
+    /// assert_eq!(None, v.nearest(&[]));
+    /// ```
+    pub fn nearest(&self, candidates: &[Vec2<T>]) -> Option<usize> {
+        let mut nearest: Option<(usize, T)> = None;
+
+        for (i, &candidate) in candidates.iter().enumerate() {
+            let distance_squared = (candidate - *self).length_squared();
+
+            if distance_squared.is_nan() {
+                continue;
+            }
+
+            match nearest {
+                Some((_, best)) if best <= distance_squared => {}
+                _ => nearest = Some((i, distance_squared)),
+            }
+        }
+
+        nearest.map(|(i, _)| i)
+    }
+
+    /// returns `self * mul + add` per component, computed with a fused
+    /// multiply-add that rounds only once
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates 3 new Vec2 objects
+    /// let v = Vec2::new(2.0, 3.0);
+    /// let mul = Vec2::new(4.0, 0.5);
+    /// let add = Vec2::new(1.0, -1.0);
+    ///
+    /// assert_eq!(v * mul + add, v.mul_add(mul, add));
+    /// assert_eq!(Vec2::new(9.0, 0.5), v.mul_add(mul, add));
+    /// ```
+    pub fn mul_add(&self, mul: Vec2<T>, add: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x.mul_add(mul.x, add.x), self.y.mul_add(mul.y, add.y))
+    }
+
+    /// returns the point a fraction `t` of the way from the Vec2 to `other`,
+    /// with `t` clamped to [0, 1] so the result never passes either end
+    ///
+    /// use `lerp_unclamped` to extrapolate beyond the 2 points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a start and an end point
+    /// let start = Vec2::new(0.0, 0.0);
+    /// let end = Vec2::new(10.0, -4.0);
+    ///
+    /// assert_eq!(end, start.lerp(end, 1.5));
+    /// assert_eq!(start, start.lerp(end, -0.5));
+    /// ```
+    pub fn lerp(&self, other: Vec2<T>, t: T) -> Vec2<T> {
+        self.lerp_unclamped(other, t.max(T::zero()).min(T::one()))
+    }
+
+    /// returns the point a fraction `t` of the way from the Vec2 to `other`,
+    /// without clamping `t`, so values outside [0, 1] extrapolate past the
+    /// ends
+    ///
+    /// use `lerp` to stay between the 2 points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a start and an end point
+    /// let start = Vec2::new(0.0, 0.0);
+    /// let end = Vec2::new(10.0, -4.0);
+    ///
+    /// assert_eq!(Vec2::new(15.0, -6.0), start.lerp_unclamped(end, 1.5));
+    /// ```
+    pub fn lerp_unclamped(&self, other: Vec2<T>, t: T) -> Vec2<T> {
+        *self + (other - *self) * t
+    }
+
+    /// returns the Vec2 blended toward `other` by a separate fraction along
+    /// each axis, taken from the matching component of `weight`
+    ///
+    /// like `lerp_unclamped`, the weights are not clamped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates 2 Vec2s to blend between
+    /// let a = Vec2::new(1.0, 2.0);
+    /// let b = Vec2::new(5.0, 6.0);
+    ///
+    /// // takes x from a and y from b
+    /// assert_eq!(Vec2::new(1.0, 6.0), a.mix(b, Vec2::new(0.0, 1.0)));
+    /// ```
+    pub fn mix(&self, other: Vec2<T>, weight: Vec2<T>) -> Vec2<T> {
+        let keep = Vec2::new(T::one() - weight.x, T::one() - weight.y);
+
+        *self * keep + other * weight
+    }
+
+    /// moves the Vec2 in place toward `other` by the fraction `t`, where 0
+    /// leaves it unchanged and 1 moves it all the way
+    ///
+    /// like `lerp_unclamped`, `t` is not clamped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a position and a target
+    /// let mut v = Vec2::new(0.0, 0.0);
+    /// let target = Vec2::new(10.0, -4.0);
+    ///
+    /// // moves half of the way there
+    /// v.lerp_assign(target, 0.5);
+    /// assert_eq!(Vec2::new(5.0, -2.0), v);
+    ///
+    /// // easing in every frame converges on the target
+    /// for _ in 0..100 {
+    ///     v.lerp_assign(target, 0.5);
+    /// }
+    ///
+    /// assert!(v.approx_eq(target, 1e-12));
+    /// ```
+    pub fn lerp_assign(&mut self, other: Vec2<T>, t: T) {
+        *self = self.lerp_unclamped(other, t);
+    }
+
+    /// returns the Vec2 scaled so its length lies within [min, max], only
+    /// computing the length once
+    ///
+    /// if `min` is greater than `max` then `max` wins. a zero length Vec2
+    /// has no direction to scale along, so it is returned unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// assert_eq!(Vec2::new(6.0, 8.0), Vec2::new(30.0, 40.0).clamp_length(2.5, 10.0));
+    /// assert_eq!(Vec2::new(1.5, 2.0), Vec2::new(0.3, 0.4).clamp_length(2.5, 10.0));
+    /// assert_eq!(Vec2::new(3.0, 4.0), Vec2::new(3.0, 4.0).clamp_length(2.5, 10.0));
+    /// ```
+    pub fn clamp_length(&self, min: T, max: T) -> Vec2<T> {
+        let length = self.length();
+
+        if length == T::zero() {
+            return *self;
+        }
+
+        let clamped = length.max(min).min(max);
+
+        if clamped == length {
+            *self
+        } else {
+            *self * (clamped / length)
+        }
+    }
+
+    /// returns the Vec2 with each component mapped from the range
+    /// [from_min, from_max] onto the range [to_min, to_max]
+    ///
+    /// inputs outside of the source range extrapolate past the target range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // maps 0 to 1 on the first axis and 0 to 10 on the rest onto 0 to 100
+    /// let from_min = Vec2::new(0.0, 0.0);
+    /// let from_max = Vec2::new(1.0, 10.0);
+    /// let to_min = Vec2::new(0.0, 0.0);
+    /// let to_max = Vec2::new(100.0, 100.0);
+    ///
+    /// let v = Vec2::new(0.5, 20.0);
+    ///
+    /// assert_eq!(Vec2::new(50.0, 200.0), v.remap(from_min, from_max, to_min, to_max));
+    /// ```
+    pub fn remap(&self, from_min: Vec2<T>, from_max: Vec2<T>, to_min: Vec2<T>, to_max: Vec2<T>) -> Vec2<T> {
+        let t = (*self - from_min) / (from_max - from_min);
+
+        to_min + (to_max - to_min) * t
+    }
+
+    /// returns the Vec2 with each component clamped into the range
+    /// [from_min, from_max] and then mapped onto the range [to_min, to_max]
+    ///
+    /// unlike `remap`, inputs outside of the source range never leave the
+    /// target range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // maps 0 to 1 on the first axis and 0 to 10 on the rest onto 0 to 100
+    /// let from_min = Vec2::new(0.0, 0.0);
+    /// let from_max = Vec2::new(1.0, 10.0);
+    /// let to_min = Vec2::new(0.0, 0.0);
+    /// let to_max = Vec2::new(100.0, 100.0);
+    ///
+    /// let v = Vec2::new(0.5, 20.0);
+    ///
+    /// assert_eq!(Vec2::new(50.0, 100.0), v.remap_clamped(from_min, from_max, to_min, to_max));
+    /// ```
+    pub fn remap_clamped(&self, from_min: Vec2<T>, from_max: Vec2<T>, to_min: Vec2<T>, to_max: Vec2<T>) -> Vec2<T> {
+        let t = ((*self - from_min) / (from_max - from_min)).clamp_scalar(T::zero(), T::one());
+
+        to_min + (to_max - to_min) * t
+    }
+
+    /// returns the Vec2 with every component clamped into the range [lo, hi]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a new Vec2
+    /// let v = Vec2::new(-5.0, 5.0);
+    ///
+    /// assert_eq!(Vec2::new(0.0, 1.0), v.clamp_scalar(0.0, 1.0));
+    /// ```
+    pub fn clamp_scalar(&self, lo: T, hi: T) -> Vec2<T> {
+        Vec2::new(self.x.max(lo).min(hi), self.y.max(lo).min(hi))
+    }
+
+    /// returns which way the path from `a` to `b` to `c` turns
+    ///
+    /// `Ordering::Greater` means counter-clockwise, `Ordering::Less` means
+    /// clockwise, and `Ordering::Equal` means the 3 points are collinear
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use vecs::Vec2;
+    ///
+    /// // creates 2 points along the x axis
+    /// let a = Vec2::new(0.0, 0.0);
+    /// let b = Vec2::new(1.0, 0.0);
+    ///
+    /// assert_eq!(Ordering::Greater, Vec2::orientation(a, b, Vec2::new(1.0, 1.0)));
+    /// assert_eq!(Ordering::Less, Vec2::orientation(a, b, Vec2::new(1.0, -1.0)));
+    /// assert_eq!(Ordering::Equal, Vec2::orientation(a, b, Vec2::new(2.0, 0.0)));
+    /// ```
+    pub fn orientation(a: Vec2<T>, b: Vec2<T>, c: Vec2<T>) -> Ordering {
+        (b - a)
+            .perp_dot(c - a)
+            .partial_cmp(&T::zero())
+            .unwrap_or(Ordering::Equal)
+    }
+
+    /// returns the vertices of the convex hull of the points in
+    /// counter-clockwise order, starting from the lowest x then lowest y
+    ///
+    /// uses Andrew's monotone chain algorithm; points lying along an edge of
+    /// the hull are left out, so collinear points give just the 2 ends, and
+    /// fewer than 3 distinct points are returned as they are
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a square with a point in the middle
+    /// let points = [
+    ///     Vec2::new(1.0, 1.0),
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(2.0, 2.0),
+    ///     Vec2::new(0.0, 2.0),
+    ///     Vec2::new(2.0, 0.0),
+    /// ];
+    ///
+    /// assert_eq!(vec![
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(2.0, 0.0),
+    ///     Vec2::new(2.0, 2.0),
+    ///     Vec2::new(0.0, 2.0),
+    /// ], Vec2::convex_hull(&points));
+    /// ```
+    pub fn convex_hull(points: &[Vec2<T>]) -> Vec<Vec2<T>> {
+        let mut sorted = points.to_vec();
+        sorted.sort_by(|a, b| {
+            a.x.partial_cmp(&b.x)
+                .unwrap_or(Ordering::Equal)
+                .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+        });
+        sorted.dedup();
+
+        if sorted.len() < 3 {
+            return sorted;
+        }
+
+        let mut hull: Vec<Vec2<T>> = Vec::with_capacity(sorted.len() + 1);
+
+        // drops points from the end of the hull while it has at least
+        // `floor` of them and adding `p` wouldn't make a counter-clockwise turn
+        let push = |hull: &mut Vec<Vec2<T>>, p: Vec2<T>, floor: usize| {
+            while hull.len() >= floor {
+                let (a, b) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+
+                if Vec2::orientation(a, b, p) == Ordering::Greater {
+                    break;
+                }
+
+                hull.pop();
+            }
+
+            hull.push(p);
+        };
+
+        // builds the lower hull left to right
+        for &p in &sorted {
+            push(&mut hull, p, 2);
+        }
+
+        // then the upper hull right to left, keeping the lower hull intact
+        let floor = hull.len() + 1;
+        for &p in sorted.iter().rev().skip(1) {
+            push(&mut hull, p, floor);
+        }
+
+        // the last point closes the loop back onto the first
+        hull.pop();
+
+        hull
+    }
+
+    /// returns the winding order of the polygon with the given vertices
+    ///
+    /// `Ordering::Greater` means counter-clockwise, `Ordering::Less` means
+    /// clockwise, and `Ordering::Equal` means the polygon is degenerate and
+    /// has no area
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use vecs::Vec2;
+    ///
+    /// // creates a counter-clockwise square
+    /// let mut square = [
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(1.0, 0.0),
+    ///     Vec2::new(1.0, 1.0),
+    ///     Vec2::new(0.0, 1.0),
+    /// ];
+    ///
+    /// assert_eq!(Ordering::Greater, Vec2::polygon_winding(&square));
+    ///
+    /// square.reverse();
+    /// assert_eq!(Ordering::Less, Vec2::polygon_winding(&square));
+    /// ```
+    pub fn polygon_winding(points: &[Vec2<T>]) -> Ordering {
+        Vec2::shoelace(points)
+            .partial_cmp(&T::zero())
+            .unwrap_or(Ordering::Equal)
+    }
+
+    /// returns the signed area of the polygon with the given vertices, using
+    /// the shoelace formula
+    ///
+    /// the area is positive for counter-clockwise winding and negative for
+    /// clockwise winding, so take its absolute value for the plain area
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a counter-clockwise unit square
+    /// let mut square = [
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(1.0, 0.0),
+    ///     Vec2::new(1.0, 1.0),
+    ///     Vec2::new(0.0, 1.0),
+    /// ];
+    ///
+    /// assert_eq!(1.0, Vec2::polygon_area(&square));
+    ///
+    /// square.reverse();
+    /// assert_eq!(-1.0, Vec2::polygon_area(&square));
+    /// ```
+    pub fn polygon_area(points: &[Vec2<T>]) -> T {
+        Vec2::shoelace(points) / (T::one() + T::one())
+    }
+
+    /// returns the area weighted centroid of the polygon with the given
+    /// vertices, or `None` if the polygon has no area
+    ///
+    /// this is the polygon's center of mass, which differs from the average
+    /// of its vertices whenever they aren't spread evenly
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates an L shaped polygon
+    /// let l = [
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(2.0, 0.0),
+    ///     Vec2::new(2.0, 1.0),
+    ///     Vec2::new(1.0, 1.0),
+    ///     Vec2::new(1.0, 2.0),
+    ///     Vec2::new(0.0, 2.0),
+    /// ];
+    ///
+    /// let c = Vec2::polygon_centroid(&l).unwrap();
+    ///
+    /// assert!(c.approx_eq(Vec2::new(5.0 / 6.0, 5.0 / 6.0), 1e-12));
+    /// assert_eq!(None, Vec2::polygon_centroid(&l[..2]));
+    /// ```
+    pub fn polygon_centroid(points: &[Vec2<T>]) -> Option<Vec2<T>> {
+        let twice_area = Vec2::shoelace(points);
+
+        if twice_area == T::zero() {
+            return None;
+        }
+
+        let mut sum = Vec2::new(T::zero(), T::zero());
+
+        for (i, &a) in points.iter().enumerate() {
+            let b = points[(i + 1) % points.len()];
+            sum = sum + (a + b) * a.perp_dot(b);
+        }
+
+        Some(sum / (twice_area + twice_area + twice_area))
+    }
+
+    /// returns the point at `t` on the cubic Hermite curve from `p0` to
+    /// `p1`, leaving with the tangent `m0` and arriving with the tangent `m1`
+    ///
+    /// `t` runs from 0 at `p0` to 1 at `p1`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates the endpoints and their tangents
+    /// let p0 = Vec2::new(0.0, 0.0);
+    /// let m0 = Vec2::new(1.0, 0.0);
+    /// let p1 = Vec2::new(1.0, 1.0);
+    /// let m1 = Vec2::new(0.0, 1.0);
+    ///
+    /// assert_eq!(p0, Vec2::hermite(p0, m0, p1, m1, 0.0));
+    /// assert_eq!(p1, Vec2::hermite(p0, m0, p1, m1, 1.0));
+    /// ```
+    pub fn hermite(p0: Vec2<T>, m0: Vec2<T>, p1: Vec2<T>, m1: Vec2<T>, t: T) -> Vec2<T> {
+        let two = T::one() + T::one();
+        let three = two + T::one();
+
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        let h00 = two * t3 - three * t2 + T::one();
+        let h10 = t3 - two * t2 + t;
+        let h01 = three * t2 - two * t3;
+        let h11 = t3 - t2;
+
+        p0 * h00 + m0 * h10 + p1 * h01 + m1 * h11
+    }
+
+    /// returns a point distributed uniformly over the area of a circle
+    /// centered on the origin with the given radius
+    ///
+    /// `rng` is called for uniformly distributed values in [0, 1), so any
+    /// random number generator can be plugged in. the distance from the
+    /// center is the square root of a uniform value, since picking it
+    /// uniformly would cluster points near the center
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // a tiny generator for the example, use a real one in practice
+    /// let mut seed = 12345_u32;
+    /// let mut rng = || {
+    ///     seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+    ///     seed as f64 / 4294967296.0
+    /// };
+    ///
+    /// let p = Vec2::random_in_circle(&mut rng, 5.0);
+    ///
+    /// assert!(p.length() <= 5.0);
+    /// ```
+    pub fn random_in_circle<R: FnMut() -> T>(rng: &mut R, radius: T) -> Vec2<T> where T: FloatConst {
+        let distance = radius * rng().sqrt();
+        let angle = (T::PI() + T::PI()) * rng();
+
+        Vec2::from_angle_length(angle, distance)
+    }
+
+    /// returns the foot of the perpendicular from `point` onto the infinite
+    /// line through `a` and `b`
+    ///
+    /// unlike a segment, the line extends past both points. if `a` and `b`
+    /// are the same point there is no line, so `a` is returned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // a line through 2 points on the x axis
+    /// let a = Vec2::new(0.0, 0.0);
+    /// let b = Vec2::new(1.0, 0.0);
+    ///
+    /// // the foot lies well past b
+    /// let foot = Vec2::closest_point_on_line(Vec2::new(5.0, 3.0), a, b);
+    ///
+    /// assert_eq!(Vec2::new(5.0, 0.0), foot);
+    /// assert_eq!(a, Vec2::closest_point_on_line(Vec2::new(5.0, 3.0), a, a));
+    /// ```
+    pub fn closest_point_on_line(point: Vec2<T>, a: Vec2<T>, b: Vec2<T>) -> Vec2<T> {
+        let direction = b - a;
+        let length_squared = direction.length_squared();
+
+        if length_squared == T::zero() {
+            return a;
+        }
+
+        a + direction * ((point - a).dot(direction) / length_squared)
+    }
+
+    /// returns twice the signed area of the polygon using the shoelace
+    /// formula, positive for counter-clockwise winding
+    fn shoelace(points: &[Vec2<T>]) -> T {
+        let mut sum = T::zero();
+
+        for (i, &a) in points.iter().enumerate() {
+            let b = points[(i + 1) % points.len()];
+            sum = sum + a.perp_dot(b);
+        }
+
+        sum
+    }
+
+    /// compares the lengths of 2 Vec2s, for sorting vectors by magnitude
+    ///
+    /// squared lengths are compared, so no square roots are taken. NaN
+    /// lengths are treated as equal to each other and greater than every
+    /// other length, so the ordering stays total
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates some vectors of different lengths
+    /// let mut v = [Vec2::new(3.0, 4.0), Vec2::new(f64::NAN, 0.0), Vec2::new(0.0, -1.0), Vec2::new(2.0, 0.0)];
+    ///
+    /// v.sort_by(|a, b| a.cmp_by_length(b));
+    ///
+    /// assert_eq!(Vec2::new(0.0, -1.0), v[0]);
+    /// assert_eq!(Vec2::new(2.0, 0.0), v[1]);
+    /// assert_eq!(Vec2::new(3.0, 4.0), v[2]);
+    /// assert!(v[3].x().is_nan());
+    /// ```
+    pub fn cmp_by_length(&self, other: &Vec2<T>) -> Ordering {
+        let a = self.length_squared();
+        let b = other.length_squared();
+
+        match (a.is_nan(), b.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => a.partial_cmp(&b).unwrap(),
+        }
+    }
+
+    /// returns the velocity after hitting a surface with the unit normal
+    /// `normal`, handling the bounce and the sliding separately
+    ///
+    /// the part of the velocity along the normal is reversed and scaled by
+    /// `restitution`, and the part along the surface is scaled by
+    /// `1 - friction`. `normal` must be unit length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // a velocity hitting the floor at an angle
+    /// let v = Vec2::new(4.0, -2.0);
+    /// let floor = Vec2::new(0.0, 1.0);
+    ///
+    /// assert_eq!(Vec2::new(3.0, 1.0), v.resolve_surface(floor, 0.5, 0.25));
+    ///
+    /// // full friction only keeps the bounce, no restitution only keeps the slide
+    /// assert_eq!(Vec2::new(0.0, 2.0), v.resolve_surface(floor, 1.0, 1.0));
+    /// assert_eq!(Vec2::new(4.0, 0.0), v.resolve_surface(floor, 0.0, 0.0));
+    /// ```
+    pub fn resolve_surface(&self, normal: Vec2<T>, restitution: T, friction: T) -> Vec2<T> {
+        let normal_part = normal * self.dot(normal);
+        let tangent_part = *self - normal_part;
+
+        tangent_part * (T::one() - friction) - normal_part * restitution
+    }
+
+    /// returns the Vec2 with each component raised to at least `s`
+    ///
+    /// # Examples
+    ///
     /// ```
     /// use vecs::Vec2;
-    /// 
-    /// // creates a new Vec2 called v1
-    /// let v1 = Vec2::new(1.0, 2.0);
-    /// 
-    /// // creates a new Vec2 call v2
-    /// let v2 = Vec2::new(10.0, 20.0);
+    ///
+    /// // creates a mixed sign Vec2
+    /// let v = Vec2::new(-3.0, 2.0);
+    ///
+    /// assert_eq!(Vec2::new(0.0, 2.0), v.max_scalar(0.0));
     /// ```
-    pub fn new(x: T, y: T) -> Vec2<T> {
-        Vec2 { x, y }
+    pub fn max_scalar(&self, s: T) -> Vec2<T> {
+        Vec2::new(self.x.max(s), self.y.max(s))
     }
 
-    /// returns the dot product of 2 2D vectors
-    /// 
+    /// returns the Vec2 with each component lowered to at most `s`
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecs::Vec2;
-    /// 
-    /// // creates 2 new Vec2 objects
-    /// let v1 = Vec2::new(1.0, 2.0);
-    /// let v2 = Vec2::new(1.0, 2.0);
-    /// 
-    /// // stores their dot product
-    /// let d = v1.dot(v2);
-    /// 
-    /// assert_eq!(5.0, d);
+    ///
+    /// // creates a mixed sign Vec2
+    /// let v = Vec2::new(-3.0, 2.0);
+    ///
+    /// assert_eq!(Vec2::new(-3.0, 0.0), v.min_scalar(0.0));
     /// ```
-    pub fn dot(&self, other: Vec2<T>) -> T {
-        self.x * other.x + self.y * other.y
+    pub fn min_scalar(&self, s: T) -> Vec2<T> {
+        Vec2::new(self.x.min(s), self.y.min(s))
     }
 
-    /// returns the length of the Vec2
-    /// 
+    /// returns the Vec2 scaled by a different factor along each axis
+    ///
+    /// this is the same as multiplying 2 Vec2s with the `*` operator
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecs::Vec2;
-    /// 
-    /// // creates a new Vec2
-    /// let v = Vec2::new(10.0, 10.0);
-    /// 
-    /// // gets its length
-    /// let len = v.length();
-    /// 
-    /// assert_eq!(f64::sqrt(200.0), len);
+    ///
+    /// // creates a Vec2 and per axis scale factors
+    /// let v = Vec2::new(3.0, 4.0);
+    /// let factors = Vec2::new(2.0, 0.5);
+    ///
+    /// assert_eq!(Vec2::new(6.0, 2.0), v.scale(factors));
+    /// assert_eq!(v * factors, v.scale(factors));
     /// ```
-    pub fn length(&self) -> T {
-        (self.x.powi(2) + self.y.powi(2)).sqrt()
+    pub fn scale(&self, factors: Vec2<T>) -> Vec2<T> {
+        *self * factors
     }
 
-    /// returns the squared length of the Vec2
-    /// 
+    /// returns true if the axis aligned boxes `min_a..max_a` and
+    /// `min_b..max_b` overlap on both axes
+    ///
+    /// boxes that only touch along an edge or at a corner count as
+    /// overlapping
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecs::Vec2;
-    /// 
-    /// // creates a new Vec2
-    /// let v = Vec2::new(10.0, 10.0);
-    /// 
-    /// // gets its length
-    /// let len = v.length_squared();
-    /// 
-    /// assert_eq!(200.0, len);
+    ///
+    /// // creates a unit box at the origin
+    /// let (min, max) = (Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
+    ///
+    /// // overlapping, touching and disjoint boxes
+    /// assert!(Vec2::aabb_overlap(min, max, Vec2::new(0.5, 0.5), Vec2::new(2.0, 2.0)));
+    /// assert!(Vec2::aabb_overlap(min, max, Vec2::new(1.0, 0.0), Vec2::new(2.0, 1.0)));
+    /// assert!(!Vec2::aabb_overlap(min, max, Vec2::new(1.5, 0.0), Vec2::new(2.0, 1.0)));
     /// ```
-    pub fn length_squared(&self) -> T {
-        self.x.powi(2) + self.y.powi(2)
+    pub fn aabb_overlap(min_a: Vec2<T>, max_a: Vec2<T>, min_b: Vec2<T>, max_b: Vec2<T>) -> bool {
+        min_a.x <= max_b.x && min_b.x <= max_a.x && min_a.y <= max_b.y && min_b.y <= max_a.y
     }
 
-    /// returns the normal of the Vec2 in (-y, x) format
-    /// 
+    /// returns true if `point` lies inside the axis aligned box `min..max`
+    ///
+    /// the test is inclusive, so points on an edge or corner of the box
+    /// count as contained
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecs::Vec2;
-    /// 
-    /// // creates a new Vec2
-    /// let v = Vec2::new(4.0, 9.0);
-    /// 
-    /// // stores it's normal
-    /// let normal = v.normal();
-    /// 
-    /// assert_eq!(Vec2::new(-9.0, 4.0), normal);
+    ///
+    /// // creates a unit box at the origin
+    /// let (min, max) = (Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
+    ///
+    /// // a point inside, on an edge and outside the box
+    /// assert!(Vec2::aabb_contains(min, max, Vec2::new(0.5, 0.5)));
+    /// assert!(Vec2::aabb_contains(min, max, Vec2::new(1.0, 0.5)));
+    /// assert!(!Vec2::aabb_contains(min, max, Vec2::new(1.5, 0.5)));
     /// ```
-    pub fn normal(&self) -> Vec2<T> {
-        Vec2::new(-self.y, self.x)
+    pub fn aabb_contains(min: Vec2<T>, max: Vec2<T>, point: Vec2<T>) -> bool {
+        min.x <= point.x && point.x <= max.x && min.y <= point.y && point.y <= max.y
     }
 
-    /// returns the normalized the Vec2
-    /// 
+    /// grows the axis aligned box `min..max` so that it contains `point`
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecs::Vec2;
-    /// 
-    /// // creates a new Vec3
-    /// let v = Vec2::new(100.0, 0.0);
-    /// 
-    /// // stores the normalized Vec3
-    /// let n = v.normalize();
-    /// 
-    /// assert_eq!(Vec2::new(1.0, 0.0), n);
+    ///
+    /// // starts the bounds at a single point
+    /// let mut min = Vec2::new(1.0, 1.0);
+    /// let mut max = min;
+    ///
+    /// Vec2::aabb_expand(&mut min, &mut max, Vec2::new(-2.0, 3.0));
+    ///
+    /// assert_eq!(Vec2::new(-2.0, 1.0), min);
+    /// assert_eq!(Vec2::new(1.0, 3.0), max);
     /// ```
-    pub fn normalize(&self) -> Vec2<T> {
-        let length = self.length();
+    pub fn aabb_expand(min: &mut Vec2<T>, max: &mut Vec2<T>, point: Vec2<T>) {
+        *min = Vec2::new(min.x.min(point.x), min.y.min(point.y));
+        *max = Vec2::new(max.x.max(point.x), max.y.max(point.y));
+    }
 
-        *self / length
+    /// returns the point a fraction `t` of the way along the path through
+    /// the given points, or None if there are no points
+    ///
+    /// `t` is clamped to [0, 1] and spread evenly over the segments by
+    /// count, so every segment takes the same share of `t` whatever its
+    /// length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a path of 2 segments with different lengths
+    /// let path = [Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0), Vec2::new(2.0, 8.0)];
+    ///
+    /// assert_eq!(Some(Vec2::new(0.0, 0.0)), Vec2::lerp_path(&path, 0.0));
+    /// assert_eq!(Some(Vec2::new(2.0, 0.0)), Vec2::lerp_path(&path, 0.5));
+    /// assert_eq!(Some(Vec2::new(2.0, 4.0)), Vec2::lerp_path(&path, 0.75));
+    /// assert_eq!(Some(Vec2::new(2.0, 8.0)), Vec2::lerp_path(&path, 1.0));
+    /// assert_eq!(None, Vec2::<f64>::lerp_path(&[], 0.5));
+    /// ```
+    pub fn lerp_path(points: &[Vec2<T>], t: T) -> Option<Vec2<T>> {
+        if points.len() < 2 {
+            return points.first().copied();
+        }
+
+        let last = points.len() - 2;
+        let scaled = t.max(T::zero()).min(T::one()) * T::from(last + 1).unwrap();
+        let segment = scaled.floor().to_usize().unwrap_or(0).min(last);
+
+        let start = points[segment];
+        let local = scaled - T::from(segment).unwrap();
+
+        Some(start + (points[segment + 1] - start) * local)
     }
-    
-    /// returns the absolute version of the Vec2
-    /// 
+
+    /// returns the index of the largest component of the Vec2
+    ///
+    /// ties go to the lowest index, NaN components are skipped, and 0 is
+    /// returned if every component is NaN
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecs::Vec2;
-    /// 
+    ///
     /// // creates a new Vec2
-    /// let v = Vec2::new(-12.0, 15.0);
-    /// 
-    /// // stores it's absolute variant
-    /// let a = v.abs();
-    /// 
-    /// assert_eq!(Vec2::new(12.0, 15.0), a);
+    /// let v = Vec2::new(1.0, 3.0);
+    ///
+    /// assert_eq!(1, v.max_axis());
+    /// assert_eq!(0, Vec2::new(2.0, 2.0).max_axis());
     /// ```
-    pub fn abs(&self) -> Vec2<T> {
-        Vec2::new(self.x.abs(), self.y.abs())
+    pub fn max_axis(&self) -> usize {
+        self.enumerate_components()
+            .filter(|&(_, c)| !c.is_nan())
+            .fold(None, |best, (i, c)| match best {
+                Some((_, b)) if c <= b => best,
+                _ => Some((i, c)),
+            })
+            .map_or(0, |(i, _)| i)
     }
 
-    /// gets the x value of the Vec2
+    /// returns the index of the smallest component of the Vec2
+    ///
+    /// ties go to the lowest index, NaN components are skipped, and 0 is
+    /// returned if every component is NaN
     ///
     /// # Examples
     ///
@@ -151,48 +2351,113 @@ impl<T: Float + Copy> Vec2<T> {
     /// use vecs::Vec2;
     ///
     /// // creates a new Vec2
-    /// let v = Vec2::new(15.0, 7.0);
+    /// let v = Vec2::new(1.0, 3.0);
     ///
-    /// assert_eq!(15.0, v.x());
+    /// assert_eq!(0, v.min_axis());
+    /// assert_eq!(0, Vec2::new(2.0, 2.0).min_axis());
     /// ```
-    pub fn x(&self) -> T {
-        self.x
+    pub fn min_axis(&self) -> usize {
+        self.enumerate_components()
+            .filter(|&(_, c)| !c.is_nan())
+            .fold(None, |best, (i, c)| match best {
+                Some((_, b)) if c >= b => best,
+                _ => Some((i, c)),
+            })
+            .map_or(0, |(i, _)| i)
     }
 
-    /// gets the y value of the Vec2
+    /// returns the Vec2 with the magnitude of each component limited to the
+    /// matching component of `max`, keeping its sign
+    ///
+    /// the components of `max` are expected to be non-negative
     ///
     /// # Examples
     ///
     /// ```
     /// use vecs::Vec2;
     ///
-    /// // creates a new Vec2
-    /// let v = Vec2::new(15.0, 7.0);
+    /// // creates a velocity and its per axis limits
+    /// let velocity = Vec2::new(-5.0, 0.5);
+    /// let limits = Vec2::new(2.0, 1.0);
     ///
-    /// assert_eq!(7.0, v.y());
+    /// assert_eq!(Vec2::new(-2.0, 0.5), velocity.clamp_abs(limits));
     /// ```
-    pub fn y(&self) -> T {
-        self.y
+    pub fn clamp_abs(&self, max: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x.max(-max.x).min(max.x), self.y.max(-max.y).min(max.y))
     }
 
-    /// sets the x and y values of the Vec2
-    /// 
+    /// returns the Vec2 with any motion into a surface with the unit
+    /// `normal` removed, leaving the motion along and away from it
+    ///
+    /// unlike projecting onto the surface, motion away from it is kept, so a
+    /// character sliding along a wall can still step off of it
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecs::Vec2;
-    /// 
+    ///
+    /// // creates a floor facing up
+    /// let normal = Vec2::new(0.0, 1.0);
+    ///
+    /// // moving into the floor slides along it
+    /// assert_eq!(Vec2::new(3.0, 0.0), Vec2::new(3.0, -2.0).slide(normal));
+    ///
+    /// // moving away from the floor is untouched
+    /// assert_eq!(Vec2::new(3.0, 2.0), Vec2::new(3.0, 2.0).slide(normal));
+    /// ```
+    pub fn slide(&self, normal: Vec2<T>) -> Vec2<T> {
+        *self - normal * self.dot(normal).min(T::zero())
+    }
+
+    /// returns, for each component, whichever of the 2 Vec2s has the larger
+    /// absolute value, keeping its sign
+    ///
+    /// ties keep the component of the Vec2 itself
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
+    /// // creates a held peak and a new sample
+    /// let peak = Vec2::new(-5.0, 1.0);
+    /// let sample = Vec2::new(2.0, -3.0);
+    ///
+    /// assert_eq!(Vec2::new(-5.0, -3.0), peak.max_abs(sample));
+    /// ```
+    pub fn max_abs(&self, other: Vec2<T>) -> Vec2<T> {
+        let pick = |a: T, b: T| if b.abs() > a.abs() { b } else { a };
+
+        Vec2::new(pick(self.x, other.x), pick(self.y, other.y))
+    }
+}
+
+impl Vec2<f32> {
+    /// returns an approximately normalized Vec2 using a fast inverse square
+    /// root estimate refined by a single Newton step
+    ///
+    /// the length of the result is within about 0.2% of 1, giving up some
+    /// accuracy compared to `normalize` for speed, and a zero vector stays
+    /// zero rather than becoming NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec2;
+    ///
     /// // creates a new Vec2
-    /// let mut v = Vec2::new(9.0, 7.0);
-    /// 
-    /// // gives v new values
-    /// v.set(5.0, 0.0);
-    /// 
-    /// assert_eq!(Vec2::new(5.0, 0.0), v);
+    /// let v = Vec2::new(3.0_f32, 4.0);
+    ///
+    /// assert!((v.normalize_fast() - v.normalize()).length() < 1e-2);
     /// ```
-    pub fn set(&mut self, x: T, y: T) {
-        self.x = x;
-        self.y = y;
+    pub fn normalize_fast(&self) -> Vec2<f32> {
+        let length_squared = self.length_squared();
+
+        let estimate = f32::from_bits(0x5f3759df - (length_squared.to_bits() >> 1));
+        let inv_length = estimate * (1.5 - 0.5 * length_squared * estimate * estimate);
+
+        *self * inv_length
     }
 }
 
@@ -212,6 +2477,30 @@ impl<T: Float> Sub for Vec2<T> {
     }
 }
 
+/// adds the scalar to every component of the Vec2
+///
+/// only `Vec2 + T` is provided, writing the scalar on the left
+/// (`T + Vec2`) would need a separate impl for each of f32 and f64
+impl<T: Float> Add<T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, rhs: T) -> Self::Output {
+        Self {x: self.x + rhs, y: self.y + rhs}
+    }
+}
+
+/// subtracts the scalar from every component of the Vec2
+///
+/// only `Vec2 - T` is provided, writing the scalar on the left
+/// (`T - Vec2`) would need a separate impl for each of f32 and f64
+impl<T: Float> Sub<T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: T) -> Self::Output {
+        Self {x: self.x - rhs, y: self.y - rhs}
+    }
+}
+
 impl<T: Float + AddAssign> AddAssign for Vec2<T> {
     fn add_assign(&mut self, rhs: Self) {
         self.x += rhs.x;
@@ -235,14 +2524,6 @@ impl<T: Float + Mul + Copy> Mul<T> for Vec2<T> {
 
 }
 
-impl<T: Float> Mul<Vec2<T>> for f32 where f32: Mul<T, Output = T> {
-    type Output = Vec2<T>;
-
-    fn mul(self, rhs: Vec2<T>) -> Self::Output {
-        Vec2::new(self * rhs.x, self * rhs.y)
-    }
-}
-
 impl<T: Float + Mul + Copy> Mul<Vec2<T>> for Vec2<T> {
     type Output = Vec2<T>;
     
@@ -261,14 +2542,6 @@ impl<T: Float + Div + Copy> Div<T> for Vec2<T> {
 
 }
 
-impl<T: Float> Div<Vec2<T>> for f32 where f32: Div<T, Output = T> {
-    type Output = Vec2<T>;
-
-    fn div(self, rhs: Vec2<T>) -> Self::Output {
-        Vec2::new(self / rhs.x, self / rhs.y)
-    }
-}
-
 impl<T: Float + Div + Copy> Div<Vec2<T>> for Vec2<T> {
     type Output = Vec2<T>;
     
@@ -278,6 +2551,30 @@ impl<T: Float + Div + Copy> Div<Vec2<T>> for Vec2<T> {
 
 }
 
+/// implements multiplying and dividing a scalar on the left by a Vec2 of
+/// the same float type, so `2.0 * v` works as well as `v * 2.0`
+macro_rules! impl_scalar_lhs {
+    ($($t:ty),*) => {$(
+        impl Mul<Vec2<$t>> for $t {
+            type Output = Vec2<$t>;
+
+            fn mul(self, rhs: Vec2<$t>) -> Self::Output {
+                Vec2::new(self * rhs.x, self * rhs.y)
+            }
+        }
+
+        impl Div<Vec2<$t>> for $t {
+            type Output = Vec2<$t>;
+
+            fn div(self, rhs: Vec2<$t>) -> Self::Output {
+                Vec2::new(self / rhs.x, self / rhs.y)
+            }
+        }
+    )*};
+}
+
+impl_scalar_lhs!(f32, f64);
+
 impl<T: Float> Neg for Vec2<T> {
     type Output = Vec2<T>;
 
@@ -286,7 +2583,84 @@ impl<T: Float> Neg for Vec2<T> {
     }
 }
 
-impl<T: Float + fmt::Display> fmt::Display for Vec2<T> {
+impl<T: Copy> AsRef<[T]> for Vec2<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: Copy> AsMut<[T]> for Vec2<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+/// builds a Vec2 from the first 2 items of an iterator
+///
+/// any items after the first 2 are ignored
+///
+/// # Panics
+///
+/// panics if the iterator yields fewer than 2 items
+impl<T> FromIterator<T> for Vec2<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let mut next = || iter.next().expect("not enough items to build a Vec2");
+        let x = next();
+        let y = next();
+
+        Vec2 { x, y }
+    }
+}
+
+impl<T: Eq> Eq for Vec2<T> {}
+
+/// orders Vec2s lexicographically, comparing x then y
+///
+/// this has no geometric meaning, it only exists so vectors of integers
+/// can be sorted deterministically or used as keys in ordered collections
+impl<T: Ord> Ord for Vec2<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.x.cmp(&other.x)
+            .then_with(|| self.y.cmp(&other.y))
+    }
+}
+
+impl<T: Ord> PartialOrd for Vec2<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// the alternate form (`{:#?}`) prints one component per line with the
+/// values right aligned, the regular form matches the derived output
+impl<T: fmt::Debug> fmt::Debug for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !f.alternate() {
+            return f.debug_struct("Vec2")
+                .field("x", &self.x)
+                .field("y", &self.y)
+                .finish();
+        }
+
+        let precision = f.precision();
+        let component = |c: &T| match precision {
+            Some(p) => format!("{:.*?}", p, c),
+            None => format!("{:?}", c),
+        };
+
+        let x = component(&self.x);
+        let y = component(&self.y);
+        let width = x.len().max(y.len());
+
+        writeln!(f, "Vec2 {{")?;
+        writeln!(f, "    x: {:>width$},", x, width = width)?;
+        writeln!(f, "    y: {:>width$},", y, width = width)?;
+        write!(f, "}}")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec2<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "({}, {})", self.x, self.y)
     }
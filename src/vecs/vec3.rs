@@ -1,221 +1,2412 @@
-use std::{fmt, ops::{Add, Sub, AddAssign, SubAssign, Div, Mul, Neg}};
-use num_traits::Float;
+use std::{cmp::Ordering, fmt, ops::{Add, Sub, AddAssign, SubAssign, Div, Mul, Neg}};
+use num_traits::{Float, FloatConst, Zero};
 
 /// implementation of a 3D vector
-#[derive(PartialEq, Debug, Clone, Copy)]
-pub struct Vec3<T: Float> {
+#[derive(PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct Vec3<T> {
     x: T,
     y: T,
     z: T
 }
 
-impl<T: Float + Copy> Vec3<T> {
+impl<T: Copy> Vec3<T> {
     /// returns a new Vec3 with the specified coordinates
     ///
     /// # Examples
-    /// 
+    /// 
+    /// ```
+    /// use vecs::Vec3;
+    /// 
+    /// // creates a new Vec3 called v1
+    /// let v1 = Vec3::new(1.0, 2.0, 3.0);
+    /// 
+    /// // creates a new Vec3 call v2
+    /// let v2 = Vec3::new(10.0, 20.0, 30.0);
+    /// ```
+    pub fn new(x: T, y: T, z: T) -> Vec3<T> {
+        Vec3 { x, y, z }
+    }
+
+    /// gets the x value of the Vec3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(15.0, 7.0, 2.0);
+    ///
+    /// assert_eq!(15.0, v.x());
+    /// ```
+    pub fn x(&self) -> T {
+        self.x
+    }
+
+    /// gets the y value of the Vec3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(15.0, 7.0, 2.0);
+    ///
+    /// assert_eq!(7.0, v.y());
+    /// ```
+    pub fn y(&self) -> T {
+        self.y
+    }
+
+    /// gets the z value of the Vec3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(15.0, 7.0, 2.0);
+    ///
+    /// assert_eq!(2.0, v.z());
+    /// ```
+    pub fn z(&self) -> T {
+        self.z
+    }
+
+    /// gets a mutable reference to the x value of the Vec3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let mut v = Vec3::new(1.0, 2.0, 3.0);
+    ///
+    /// // nudges only the x value
+    /// *v.x_mut() += 1.0;
+    ///
+    /// assert_eq!(Vec3::new(2.0, 2.0, 3.0), v);
+    /// ```
+    pub fn x_mut(&mut self) -> &mut T {
+        &mut self.x
+    }
+
+    /// gets a mutable reference to the y value of the Vec3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let mut v = Vec3::new(1.0, 2.0, 3.0);
+    ///
+    /// // nudges only the y value
+    /// *v.y_mut() += 1.0;
+    ///
+    /// assert_eq!(Vec3::new(1.0, 3.0, 3.0), v);
+    /// ```
+    pub fn y_mut(&mut self) -> &mut T {
+        &mut self.y
+    }
+
+    /// gets a mutable reference to the z value of the Vec3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let mut v = Vec3::new(1.0, 2.0, 3.0);
+    ///
+    /// // nudges only the z value
+    /// *v.z_mut() += 1.0;
+    ///
+    /// assert_eq!(Vec3::new(1.0, 2.0, 4.0), v);
+    /// ```
+    pub fn z_mut(&mut self) -> &mut T {
+        &mut self.z
+    }
+
+    /// returns an iterator over the components of the Vec3 paired with their
+    /// axis index, yielding (0, x), (1, y), then (2, z)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    ///
+    /// let pairs: Vec<(usize, f64)> = v.enumerate_components().collect();
+    ///
+    /// assert_eq!(vec![(0, 1.0), (1, 2.0), (2, 3.0)], pairs);
+    /// ```
+    pub fn enumerate_components(&self) -> impl Iterator<Item = (usize, T)> {
+        [self.x, self.y, self.z].into_iter().enumerate()
+    }
+
+    /// sets the x, y, and z values of the Vec3
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecs::Vec3;
+    /// 
+    /// // creates a new Vec3
+    /// let mut v = Vec3::new(9.0, 7.0, 1.0);
+    /// 
+    /// // gives v new values
+    /// v.set(5.0, 0.0, 8.0);
+    /// 
+    /// assert_eq!(Vec3::new(5.0, 0.0, 8.0), v);
+    /// ```
+    pub fn set(&mut self, x: T, y: T, z: T) {
+        self.x = x;
+        self.y = y;
+        self.z = z;
+    }
+
+    /// returns the components of the Vec3 as a slice, without copying
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(5.0, 7.0, 1.0);
+    ///
+    /// assert_eq!(&[5.0, 7.0, 1.0], v.as_slice());
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: Vec3 is repr(C) and made up of exactly 3 fields of type T,
+        // so it has the same layout as [T; 3]
+        unsafe { &*(self as *const Vec3<T> as *const [T; 3]) }
+    }
+
+    /// returns the components of the Vec3 as a mutable slice, without copying
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let mut v = Vec3::new(5.0, 7.0, 1.0);
+    ///
+    /// // changes the y value through the slice
+    /// v.as_mut_slice()[1] = 9.0;
+    ///
+    /// assert_eq!(Vec3::new(5.0, 9.0, 1.0), v);
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: Vec3 is repr(C) and made up of exactly 3 fields of type T,
+        // so it has the same layout as [T; 3]
+        unsafe { &mut *(self as *mut Vec3<T> as *mut [T; 3]) }
+    }
+
+    /// returns a copy of the Vec3 with the component at index `i` replaced,
+    /// where x is index 0, y is index 1, and z is index 2
+    ///
+    /// # Panics
+    ///
+    /// panics if `i` is not 0, 1, or 2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    ///
+    /// // replaces the y value
+    /// let w = v.with_component(1, 5.0);
+    ///
+    /// assert_eq!(Vec3::new(1.0, 5.0, 3.0), w);
+    /// ```
+    pub fn with_component(&self, i: usize, value: T) -> Vec3<T> {
+        match i {
+            0 => Vec3::new(value, self.y, self.z),
+            1 => Vec3::new(self.x, value, self.z),
+            2 => Vec3::new(self.x, self.y, value),
+            _ => panic!("component index {} out of range for Vec3", i),
+        }
+    }
+
+    /// returns true if the predicate holds for every component of the Vec3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(-1.0_f64, 2.0, 3.0);
+    ///
+    /// assert!(v.all(|c| c.is_finite()));
+    /// assert!(!v.all(|c| c > 0.0));
+    /// ```
+    pub fn all<F: Fn(T) -> bool>(&self, f: F) -> bool {
+        f(self.x) && f(self.y) && f(self.z)
+    }
+
+    /// returns true if the predicate holds for any component of the Vec3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(1.0_f64, -2.0, 3.0);
+    ///
+    /// assert!(v.any(|c| c < 0.0));
+    /// assert!(!v.any(|c| c.is_nan()));
+    /// ```
+    pub fn any<F: Fn(T) -> bool>(&self, f: F) -> bool {
+        f(self.x) || f(self.y) || f(self.z)
+    }
+
+    /// folds the components of the Vec3 from left to right, x, y, then z
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(v.x() + v.y() + v.z(), v.fold(0.0, |a, c| a + c));
+    /// assert_eq!(6.0, v.fold(1.0, |a, c| a * c));
+    /// ```
+    pub fn fold<A, F: Fn(A, T) -> A>(&self, init: A, f: F) -> A {
+        f(f(f(init, self.x), self.y), self.z)
+    }
+
+    /// returns the components of the Vec3 as a tuple
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(1, 2, 3);
+    ///
+    /// assert_eq!((1, 2, 3), v.to_tuple());
+    /// ```
+    pub fn to_tuple(&self) -> (T, T, T) {
+        (self.x, self.y, self.z)
+    }
+}
+
+impl<T: Copy + Mul<Output = T> + Sub<Output = T>> Vec3<T> {
+    /// returns the cross product of 2 3D vectors
+    ///
+    /// only needs multiplication and subtraction, so it builds for component
+    /// types without negation, but unsigned components only work when every
+    /// component of the result is non-negative, otherwise the subtraction
+    /// overflows and panics in debug builds
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecs::Vec3;
+    /// 
+    /// // creates 2 new Vec3 objects
+    /// let v1 = Vec3::new(3.0, 2.0, 1.0);
+    /// let v2 = Vec3::new(1.0, 2.0, 3.0);
+    /// 
+    /// // stores their cross product
+    /// let v3 = v1.cross(v2);
+    /// 
+    /// assert_eq!(Vec3::new(4.0, -8.0, 4.0), v3);
+    /// ```
+    pub fn cross(&self, other: Vec3<T>) -> Vec3<T> {
+        let x = (self.y * other.z) - (self.z * other.y);
+        let y = (self.z * other.x) - (self.x * other.z);
+        let z = (self.x * other.y) - (self.y * other.x);
+
+        Vec3::new(x, y, z)
+    }
+}
+
+impl<T: Zero + Copy + PartialEq> Vec3<T> {
+    /// returns a Vec3 with every component set to zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates an integer zero vector to accumulate into
+    /// let v: Vec3<i32> = Vec3::zero();
+    ///
+    /// assert_eq!(Vec3::new(0, 0, 0), v);
+    /// ```
+    pub fn zero() -> Vec3<T> {
+        Vec3::new(T::zero(), T::zero(), T::zero())
+    }
+
+    /// returns true if every component of the Vec3 is exactly zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// assert!(Vec3::new(0.0, 0.0, 0.0).is_zero());
+    /// assert!(!Vec3::new(1e-9, 1e-9, 1e-9).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.x == T::zero() && self.y == T::zero() && self.z == T::zero()
+    }
+}
+
+impl<T: Float + Copy> Vec3<T> {
+
+    /// returns a new unit Vec3 pointing in the direction given by `yaw` and
+    /// `pitch`, in radians
+    ///
+    /// +y is up and a yaw and pitch of 0 faces down +z, positive yaw turns
+    /// toward +x and positive pitch looks up toward +y
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// // looks straight ahead
+    /// assert_eq!(Vec3::new(0.0, 0.0, 1.0), Vec3::from_yaw_pitch(0.0, 0.0));
+    ///
+    /// // looks straight up
+    /// let up = Vec3::from_yaw_pitch(0.0, FRAC_PI_2);
+    /// assert!((up - Vec3::new(0.0, 1.0, 0.0)).length() < 1e-12);
+    ///
+    /// // turns a quarter to the right
+    /// let right = Vec3::from_yaw_pitch(FRAC_PI_2, 0.0);
+    /// assert!((right - Vec3::new(1.0, 0.0, 0.0)).length() < 1e-12);
+    /// ```
+    pub fn from_yaw_pitch(yaw: T, pitch: T) -> Vec3<T> {
+        let (sin_yaw, cos_yaw) = yaw.sin_cos();
+        let (sin_pitch, cos_pitch) = pitch.sin_cos();
+
+        Vec3::new(cos_pitch * sin_yaw, sin_pitch, cos_pitch * cos_yaw)
+    }
+
+    /// returns the yaw and pitch, in radians, of the direction of the Vec3
+    ///
+    /// uses the same convention as `from_yaw_pitch`, with yaw within
+    /// [-π, π] and pitch within [-π/2, π/2], and the length is ignored
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    /// use std::f64::consts::FRAC_PI_4;
+    ///
+    /// // looks up and to the right
+    /// let (yaw, pitch) = Vec3::new(1.0, 2.0_f64.sqrt(), 1.0).to_yaw_pitch();
+    ///
+    /// assert!((yaw - FRAC_PI_4).abs() < 1e-12);
+    /// assert!((pitch - FRAC_PI_4).abs() < 1e-12);
+    /// ```
+    pub fn to_yaw_pitch(&self) -> (T, T) {
+        let yaw = self.x.atan2(self.z);
+        let pitch = self.y.atan2(self.x.hypot(self.z));
+
+        (yaw, pitch)
+    }
+
+    /// returns the dot product of 2 3D vectors
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecs::Vec3;
+    /// 
+    /// // creates 2 new Vec3 objects
+    /// let v1 = Vec3::new(1.0, 2.0, 3.0);
+    /// let v2 = Vec3::new(1.0, 2.0, 3.0);
+    /// 
+    /// // stores their dot product
+    /// let d = v1.dot(v2);
+    /// 
+    /// assert_eq!(14.0, d);
+    /// ```
+    pub fn dot(&self, other: Vec3<T>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// returns the dot product of 2 3D vectors, taking `other` by reference
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates 2 new Vec3 objects
+    /// let v1 = Vec3::new(1.0, 2.0, 3.0);
+    /// let v2 = Vec3::new(1.0, 2.0, 3.0);
+    ///
+    /// // borrows both
+    /// let (r1, r2) = (&v1, &v2);
+    ///
+    /// assert_eq!(14.0, r1.dot_ref(r2));
+    /// ```
+    pub fn dot_ref(&self, other: &Vec3<T>) -> T {
+        self.dot(*other)
+    }
+
+    /// returns the dot product of each pair of Vec3s from the 2 slices, or
+    /// None if the slices have different lengths
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates some surface normals and a light direction for each
+    /// let normals = [Vec3::new(0.0, 1.0, 0.0), Vec3::new(1.0, 0.0, 0.0)];
+    /// let lights = [Vec3::new(0.0, 0.5, 0.5), Vec3::new(-1.0, 0.0, 0.0)];
+    ///
+    /// assert_eq!(Some(vec![0.5, -1.0]), Vec3::dot_slice(&normals, &lights));
+    /// assert_eq!(None, Vec3::dot_slice(&normals, &lights[..1]));
+    /// ```
+    pub fn dot_slice(a: &[Vec3<T>], b: &[Vec3<T>]) -> Option<Vec<T>> {
+        if a.len() != b.len() {
+            return None;
+        }
+
+        Some(a.iter().zip(b).map(|(a, b)| a.dot(*b)).collect())
+    }
+
+    /// returns the vector triple product `self × (b × c)`
+    ///
+    /// computed with the identity `b * (self · c) - c * (self · b)`, which
+    /// avoids building the intermediate cross product
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates 3 new Vec3 objects
+    /// let a = Vec3::new(1.0, 2.0, 3.0);
+    /// let b = Vec3::new(-4.0, 0.5, 2.0);
+    /// let c = Vec3::new(3.0, -1.0, 7.0);
+    ///
+    /// assert_eq!(a.cross(b.cross(c)), a.vector_triple(b, c));
+    /// ```
+    pub fn vector_triple(&self, b: Vec3<T>, c: Vec3<T>) -> Vec3<T> {
+        b * self.dot(c) - c * self.dot(b)
+    }
+
+    /// returns the length of the Vec3
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecs::Vec3;
+    /// 
+    /// // creates a new Vec3
+    /// let v = Vec3::new(10.0, 10.0, 10.0);
+    /// 
+    /// // gets its length
+    /// let len = v.length();
+    /// 
+    /// assert_eq!(f64::sqrt(300.0), len);
+    /// ```
+    pub fn length(&self) -> T {
+        (self.x.powi(2) + self.y.powi(2) + self.z.powi(2)).sqrt()
+    }
+
+    /// returns the squared length of the Vec3
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecs::Vec3;
+    /// 
+    /// // creates a new Vec3
+    /// let v = Vec3::new(10.0, 10.0, 10.0);
+    /// 
+    /// // gets its length
+    /// let len = v.length_squared();
+    /// 
+    /// assert_eq!(300.0, len);
+    /// ```
+    pub fn length_squared(&self) -> T {
+        self.x.powi(2) + self.y.powi(2) + self.z.powi(2)
+    }
+
+    /// returns the reciprocal of the length of the Vec3, `1 / length`
+    ///
+    /// a zero length Vec3 gives positive infinity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(0.0, 2.0, 0.0);
+    ///
+    /// assert_eq!(0.5, v.inv_length());
+    /// assert_eq!(f64::INFINITY, Vec3::new(0.0, 0.0, 0.0).inv_length());
+    /// ```
+    pub fn inv_length(&self) -> T {
+        self.length().recip()
+    }
+
+    /// returns the normalized the Vec3
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecs::Vec3;
+    /// 
+    /// // creates a new Vec3
+    /// let v = Vec3::new(100.0, 0.0, 0.0);
+    /// 
+    /// // stores the normalized Vec3
+    /// let n = v.normalize();
+    /// 
+    /// assert_eq!(Vec3::new(1.0, 0.0, 0.0), n);
+    /// ```
+    pub fn normalize(&self) -> Vec3<T> {
+        let length = self.length();
+
+        *self / length
+    }
+    
+    /// normalizes the Vec3 in place
+    ///
+    /// a zero length Vec3 has no direction, so it becomes NaN. use
+    /// `try_normalize_mut` to leave it unchanged instead
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let mut v = Vec3::new(3.0_f64, 4.0, 12.0);
+    ///
+    /// v.normalize_mut();
+    ///
+    /// assert!((v.length() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn normalize_mut(&mut self) {
+        *self = self.normalize();
+    }
+
+    /// normalizes the Vec3 in place, returning false and leaving it unchanged
+    /// if its computed length is zero or not finite
+    ///
+    /// this covers vectors so short that their length underflows to zero,
+    /// which would otherwise be divided into infinities and NaNs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a zero length Vec3
+    /// let mut v = Vec3::new(0.0, 0.0, 0.0);
+    ///
+    /// assert!(!v.try_normalize_mut());
+    /// assert_eq!(Vec3::new(0.0, 0.0, 0.0), v);
+    /// ```
+    pub fn try_normalize_mut(&mut self) -> bool {
+        let length = self.length();
+
+        if length == T::zero() || !length.is_finite() {
+            return false;
+        }
+
+        *self = *self / length;
+
+        true
+    }
+
+    /// normalizes every Vec3 in the slice in place, leaving any whose
+    /// computed length is zero or not finite unchanged, like
+    /// `try_normalize_mut`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates some vectors including a zero length one
+    /// let mut vectors = [Vec3::new(3.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 0.0)];
+    ///
+    /// Vec3::normalize_slice(&mut vectors);
+    ///
+    /// assert_eq!([Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 0.0)], vectors);
+    /// ```
+    pub fn normalize_slice(vectors: &mut [Vec3<T>]) {
+        for v in vectors {
+            v.try_normalize_mut();
+        }
+    }
+
+    /// returns the absolute version of the Vec3
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecs::Vec3;
+    /// 
+    /// // creates a new Vec3
+    /// let v = Vec3::new(-12.0, 15.0, -9.0);
+    /// 
+    /// // stores it's absolute variant
+    /// let a = v.abs();
+    /// 
+    /// assert_eq!(Vec3::new(12.0, 15.0, 9.0), a);
+    /// ```
+    pub fn abs(&self) -> Vec3<T> {
+        Vec3::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    /// returns the sign of each component of the Vec3 as -1, 0 or 1
+    ///
+    /// unlike `signum`, components that are exactly zero stay zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a Vec3 with a zero component
+    /// let v = Vec3::new(-3.5, 0.0, 0.25);
+    ///
+    /// assert_eq!(Vec3::new(-1.0, 0.0, 1.0), v.sign_or_zero());
+    /// ```
+    pub fn sign_or_zero(&self) -> Vec3<T> {
+        let sign = |c: T| if c == T::zero() { c } else { c.signum() };
+
+        Vec3::new(sign(self.x), sign(self.y), sign(self.z))
+    }
+
+    /// returns the Vec3 scaled down to a length of `max` if it is longer,
+    /// otherwise returns it unchanged
+    ///
+    /// this is the same operation as clamping the length to a maximum,
+    /// named after the `limit` used in steering behaviours
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates 2 new Vec3 objects
+    /// let fast = Vec3::new(0.0, 30.0, 40.0);
+    /// let slow = Vec3::new(0.0, 3.0, 4.0);
+    ///
+    /// // limits both to a length of 10
+    /// assert_eq!(Vec3::new(0.0, 6.0, 8.0), fast.limit(10.0));
+    /// assert_eq!(Vec3::new(0.0, 3.0, 4.0), slow.limit(10.0));
+    /// ```
+    pub fn limit(&self, max: T) -> Vec3<T> {
+        let length_squared = self.length_squared();
+
+        if length_squared <= max * max {
+            *self
+        } else {
+            *self * (max / length_squared.sqrt())
+        }
+    }
+
+    /// returns the Vec3 with each component wrapped into the range
+    /// [min, max), so values that leave one side come back in on the other
+    ///
+    /// wrapping uses a floored modulo, so negative coordinates wrap
+    /// correctly as well
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a position that has left the right edge of the world
+    /// let v = Vec3::new(12.0, -1.0, 5.0);
+    ///
+    /// // wraps it back into a 10x10x10 world
+    /// let w = v.wrap(Vec3::new(0.0, 0.0, 0.0), Vec3::new(10.0, 10.0, 10.0));
+    ///
+    /// assert_eq!(Vec3::new(2.0, 9.0, 5.0), w);
+    /// ```
+    pub fn wrap(&self, min: Vec3<T>, max: Vec3<T>) -> Vec3<T> {
+        let wrap = |v: T, min: T, max: T| {
+            let range = max - min;
+            let offset = v - min;
+
+            let wrapped = min + offset - range * (offset / range).floor();
+
+            // rounding can push the result just outside the range, such as a
+            // tiny negative offset landing exactly on max
+            if wrapped < min || wrapped >= max {
+                min
+            } else {
+                wrapped
+            }
+        };
+
+        Vec3::new(
+            wrap(self.x, min.x, max.x),
+            wrap(self.y, min.y, max.y),
+            wrap(self.z, min.z, max.z),
+        )
+    }
+
+    /// returns the Vec3 transformed by a 3x3 matrix
+    ///
+    /// the matrix is row-major, so `matrix[0]` is the first row and the
+    /// result is the matrix-vector product `matrix * self`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(2.0, 3.0, 4.0);
+    ///
+    /// // the identity matrix leaves it unchanged
+    /// let identity = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    /// assert_eq!(v, v.transform(identity));
+    ///
+    /// // a 90 degree counter-clockwise rotation around the z axis
+    /// let rotation = [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+    /// assert_eq!(Vec3::new(-3.0, 2.0, 4.0), v.transform(rotation));
+    /// ```
+    pub fn transform(&self, matrix: [[T; 3]; 3]) -> Vec3<T> {
+        Vec3::new(
+            matrix[0][0] * self.x + matrix[0][1] * self.y + matrix[0][2] * self.z,
+            matrix[1][0] * self.x + matrix[1][1] * self.y + matrix[1][2] * self.z,
+            matrix[2][0] * self.x + matrix[2][1] * self.y + matrix[2][2] * self.z,
+        )
+    }
+
+    /// returns `self` normalized and `other` made orthogonal to it and
+    /// normalized, re-orthogonalizing a pair of approximate axes
+    ///
+    /// if the two are parallel there is no unique orthogonal direction, so
+    /// an arbitrary unit vector perpendicular to `self` is returned instead
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates 2 roughly perpendicular axes
+    /// let a = Vec3::new(2.0_f64, 0.1, 0.0);
+    /// let b = Vec3::new(0.3, 1.0, 0.2);
+    ///
+    /// // builds an orthonormal pair from them
+    /// let (u, v) = a.gram_schmidt(b);
+    ///
+    /// assert!((u.length() - 1.0).abs() < 1e-12);
+    /// assert!((v.length() - 1.0).abs() < 1e-12);
+    /// assert!(u.dot(v).abs() < 1e-12);
+    /// ```
+    pub fn gram_schmidt(&self, other: Vec3<T>) -> (Vec3<T>, Vec3<T>) {
+        let u = self.normalize();
+        let rejection = other - u * other.dot(u);
+
+        if rejection.length_squared() <= T::epsilon() * other.length_squared() {
+            (u, u.any_orthogonal())
+        } else {
+            (u, rejection.normalize())
+        }
+    }
+
+    /// returns the Vec3 rotated toward `target` by at most `max_radians`
+    ///
+    /// if `target` is within `max_radians` it is returned as is, otherwise
+    /// the Vec3 is rotated by `max_radians` around the axis between the two,
+    /// keeping its length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// // creates a heading and a target a quarter turn away
+    /// let heading = Vec3::new(1.0, 0.0, 0.0);
+    /// let target = Vec3::new(0.0, 0.0, 1.0);
+    ///
+    /// // still turning, so only half of the way there
+    /// let turned = heading.rotate_towards(target, FRAC_PI_2 / 2.0);
+    /// assert!((turned.angle_between(heading) - FRAC_PI_2 / 2.0).abs() < 1e-12);
+    /// assert!((turned.angle_between(target) - FRAC_PI_2 / 2.0).abs() < 1e-12);
+    ///
+    /// // close enough to arrive
+    /// assert_eq!(target, heading.rotate_towards(target, FRAC_PI_2));
+    /// ```
+    pub fn rotate_towards(&self, target: Vec3<T>, max_radians: T) -> Vec3<T> {
+        let cross = self.cross(target);
+        let angle = cross.length().atan2(self.dot(target));
+
+        if angle <= max_radians {
+            return target;
+        }
+
+        // anti-parallel vectors have no unique axis, so any will do
+        let axis = if cross.length_squared() > T::zero() {
+            cross.normalize()
+        } else {
+            self.any_orthogonal()
+        };
+
+        self.rotate_around_axis(axis, max_radians)
+    }
+
+    /// returns the unit axis and angle, in radians, of the smallest rotation
+    /// that turns the direction of the Vec3 onto the direction of `target`
+    ///
+    /// parallel and anti-parallel vectors have no unique axis, so a
+    /// perpendicular one is chosen, with an angle of 0 or π respectively
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    /// use std::f64::consts::{FRAC_PI_2, PI};
+    ///
+    /// // creates 2 perpendicular directions
+    /// let from = Vec3::new(1.0, 0.0, 0.0);
+    /// let to = Vec3::new(0.0, 1.0, 0.0);
+    ///
+    /// assert_eq!((Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2), from.rotation_to(to));
+    ///
+    /// // turning around picks some perpendicular axis
+    /// let (axis, angle) = from.rotation_to(-from);
+    /// assert_eq!(0.0, axis.dot(from));
+    /// assert_eq!(PI, angle);
+    /// ```
+    pub fn rotation_to(&self, target: Vec3<T>) -> (Vec3<T>, T) {
+        let cross = self.cross(target);
+        let angle = self.angle_between_stable(target);
+
+        let axis = if cross.length_squared() > T::zero() {
+            cross.normalize()
+        } else {
+            self.any_orthogonal()
+        };
+
+        (axis, angle)
+    }
+
+    /// returns the Vec3 rotated back toward `axis` so that it lies within a
+    /// cone of half angle `max_angle` radians around it, keeping its length
+    ///
+    /// directions already inside the cone are returned unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    /// use std::f64::consts::FRAC_PI_4;
+    ///
+    /// // creates an aim direction and a forward axis
+    /// let forward = Vec3::new(0.0, 0.0, 1.0);
+    /// let aim = Vec3::new(2.0, 0.0, 0.0);
+    ///
+    /// // pulls the aim onto the edge of a 45 degree cone
+    /// let clamped = aim.clamp_to_cone(forward, FRAC_PI_4);
+    /// assert!((clamped.angle_between(forward) - FRAC_PI_4).abs() < 1e-12);
+    /// assert!((clamped.length() - 2.0).abs() < 1e-12);
+    ///
+    /// // a direction inside the cone is left alone
+    /// let inside = Vec3::new(0.1, 0.0, 1.0);
+    /// assert_eq!(inside, inside.clamp_to_cone(forward, FRAC_PI_4));
+    /// ```
+    pub fn clamp_to_cone(&self, axis: Vec3<T>, max_angle: T) -> Vec3<T> {
+        let angle = self.angle_between_stable(axis);
+
+        if angle <= max_angle {
+            return *self;
+        }
+
+        // a cone with no width only leaves the axis itself, which
+        // rotate_towards would snap to without keeping the length
+        if max_angle <= T::zero() {
+            return axis.normalize() * self.length();
+        }
+
+        self.rotate_towards(axis, angle - max_angle)
+    }
+
+    /// returns the per-component mean and population variance of the points,
+    /// or `None` if the slice is empty
+    ///
+    /// uses Welford's single pass algorithm, which stays accurate for large
+    /// coordinates where the naive sum of squares cancels catastrophically
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a small point cloud
+    /// let points = [
+    ///     Vec3::new(1.0, 10.0, 5.0),
+    ///     Vec3::new(2.0, 10.0, 5.0),
+    ///     Vec3::new(3.0, 10.0, 8.0),
+    ///     Vec3::new(4.0, 10.0, 8.0),
+    /// ];
+    ///
+    /// let (mean, variance) = Vec3::mean_variance(&points).unwrap();
+    ///
+    /// assert_eq!(Vec3::new(2.5, 10.0, 6.5), mean);
+    /// assert_eq!(Vec3::new(1.25, 0.0, 2.25), variance);
+    /// assert_eq!(None, Vec3::<f64>::mean_variance(&[]));
+    /// ```
+    pub fn mean_variance(points: &[Vec3<T>]) -> Option<(Vec3<T>, Vec3<T>)> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let zero = Vec3::new(T::zero(), T::zero(), T::zero());
+        let mut mean = zero;
+        let mut m2 = zero;
+        let mut count = T::zero();
+
+        for &point in points {
+            count = count + T::one();
+
+            let delta = point - mean;
+            mean = mean + delta / count;
+            m2 = m2 + delta * (point - mean);
+        }
+
+        Some((mean, m2 / count))
+    }
+
+    /// returns true if the 3 points lie on a line, within a tolerance
+    ///
+    /// the points count as collinear when the area of the triangle they
+    /// form is at most `epsilon`, since floats are almost never exactly
+    /// collinear
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates 3 points on a line
+    /// let a = Vec3::new(0.0, 0.0, 0.0);
+    /// let b = Vec3::new(1.0, 1.0, 1.0);
+    /// let c = Vec3::new(2.0, 2.0, 2.0);
+    ///
+    /// assert!(Vec3::are_collinear(a, b, c, 1e-9));
+    ///
+    /// // nudges the last point slightly off the line
+    /// let c = Vec3::new(2.0, 2.0, 2.001);
+    ///
+    /// assert!(Vec3::are_collinear(a, b, c, 1e-3));
+    /// assert!(!Vec3::are_collinear(a, b, c, 1e-6));
+    /// ```
+    pub fn are_collinear(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>, epsilon: T) -> bool {
+        let area = (b - a).cross(c - a).length() / (T::one() + T::one());
+
+        area <= epsilon
+    }
+
+    /// returns the index of the candidate closest to the Vec3, or `None` if
+    /// there are no candidates
+    ///
+    /// compares squared distances, so no square roots are taken. ties go to
+    /// the earliest candidate, and candidates at a NaN distance are skipped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a point and some nodes to snap to
+    /// let v = Vec3::new(2.0, 2.0, 2.0);
+    /// let nodes = [
+    ///     Vec3::new(0.0, 0.0, 0.0),
+    ///     Vec3::new(3.0, 1.0, 2.0),
+    ///     Vec3::new(5.0, 5.0, 5.0),
+    /// ];
+    ///
+    /// assert_eq!(Some(1), v.nearest(&nodes));
+    /// assert_eq!(None, v.nearest(&[]));
+    /// ```
+    pub fn nearest(&self, candidates: &[Vec3<T>]) -> Option<usize> {
+        let mut nearest: Option<(usize, T)> = None;
+
+        for (i, &candidate) in candidates.iter().enumerate() {
+            let distance_squared = (candidate - *self).length_squared();
+
+            if distance_squared.is_nan() {
+                continue;
+            }
+
+            match nearest {
+                Some((_, best)) if best <= distance_squared => {}
+                _ => nearest = Some((i, distance_squared)),
+            }
+        }
+
+        nearest.map(|(i, _)| i)
+    }
+
+    /// returns the signed distance from the point to the plane through
+    /// `plane_point` with the unit normal `plane_normal`
+    ///
+    /// the distance is positive on the side the normal points toward. a
+    /// normal that isn't unit length scales the result by its length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // the xy plane
+    /// let point = Vec3::new(0.0, 0.0, 0.0);
+    /// let normal = Vec3::new(0.0, 0.0, 1.0);
+    ///
+    /// assert_eq!(2.0, Vec3::new(5.0, 1.0, 2.0).signed_distance_to_plane(point, normal));
+    /// assert_eq!(-3.0, Vec3::new(5.0, 1.0, -3.0).signed_distance_to_plane(point, normal));
+    /// ```
+    pub fn signed_distance_to_plane(&self, plane_point: Vec3<T>, plane_normal: Vec3<T>) -> T {
+        (*self - plane_point).dot(plane_normal)
+    }
+
+    /// returns the squared perpendicular distance from the point to the
+    /// plane through `plane_point` with the normal `plane_normal`
+    ///
+    /// the normal doesn't need to be unit length, and no square root is
+    /// taken, which keeps comparisons in tight loops cheap
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // the xy plane, with a normal that isn't unit length
+    /// let point = Vec3::new(0.0, 0.0, 0.0);
+    /// let normal = Vec3::new(0.0, 0.0, 4.0);
+    ///
+    /// let v = Vec3::new(5.0, 1.0, -3.0);
+    ///
+    /// // matches the squared signed distance using the unit normal
+    /// let d = v.signed_distance_to_plane(point, normal.normalize());
+    ///
+    /// assert_eq!(9.0, v.distance_squared_to_plane(point, normal));
+    /// assert_eq!(d * d, v.distance_squared_to_plane(point, normal));
+    /// ```
+    pub fn distance_squared_to_plane(&self, plane_point: Vec3<T>, plane_normal: Vec3<T>) -> T {
+        let distance = self.signed_distance_to_plane(plane_point, plane_normal);
+
+        distance * distance / plane_normal.length_squared()
+    }
+
+    /// returns the point mirrored to the other side of the plane through
+    /// `plane_point` with the unit normal `plane_normal`
+    ///
+    /// `plane_normal` must be unit length, otherwise the point is moved by
+    /// the wrong distance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // the xy plane
+    /// let point = Vec3::new(0.0, 0.0, 0.0);
+    /// let normal = Vec3::new(0.0, 0.0, 1.0);
+    ///
+    /// // mirrors a point one unit above it to one unit below
+    /// let v = Vec3::new(3.0, 4.0, 1.0);
+    ///
+    /// assert_eq!(Vec3::new(3.0, 4.0, -1.0), v.mirror_across_plane(point, normal));
+    /// ```
+    pub fn mirror_across_plane(&self, plane_point: Vec3<T>, plane_normal: Vec3<T>) -> Vec3<T> {
+        let distance = self.signed_distance_to_plane(plane_point, plane_normal);
+
+        *self - plane_normal * (distance + distance)
+    }
+
+    /// returns the closest point to `point` on the ray starting at `origin`
+    /// and heading along `dir`
+    ///
+    /// unlike a line, the ray only extends forward, so points behind the
+    /// origin snap to the origin itself. a zero length `dir` also gives the
+    /// origin
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // a ray along the x axis
+    /// let origin = Vec3::new(1.0, 0.0, 0.0);
+    /// let dir = Vec3::new(2.0, 0.0, 0.0);
+    ///
+    /// let ahead = Vec3::closest_point_on_ray(Vec3::new(4.0, 3.0, 0.0), origin, dir);
+    /// let behind = Vec3::closest_point_on_ray(Vec3::new(-4.0, 3.0, 0.0), origin, dir);
+    ///
+    /// assert_eq!(Vec3::new(4.0, 0.0, 0.0), ahead);
+    /// assert_eq!(origin, behind);
+    /// ```
+    pub fn closest_point_on_ray(point: Vec3<T>, origin: Vec3<T>, dir: Vec3<T>) -> Vec3<T> {
+        let length_squared = dir.length_squared();
+
+        if length_squared == T::zero() {
+            return origin;
+        }
+
+        let t = ((point - origin).dot(dir) / length_squared).max(T::zero());
+
+        origin + dir * t
+    }
+
+    /// returns the distance between 2 points
+    ///
+    /// like `hypot`, the offset is scaled by its largest component before
+    /// squaring, so huge or tiny separations don't overflow to infinity or
+    /// underflow to zero the way `(a - b).length()` can
+    ///
+    /// this doesn't help with cancellation: the points are still subtracted
+    /// first, so a small separation between huge positions is only as
+    /// precise as the spacing of floats at that magnitude, the same as the
+    /// naive distance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates 2 points far from the origin
+    /// let a = Vec3::new(1.0e20_f32, 1.0e20, 0.0);
+    /// let b = Vec3::new(4.0e20_f32, 5.0e20, 0.0);
+    ///
+    /// // squaring the offset overflows an f32
+    /// assert_eq!(f32::INFINITY, (b - a).length());
+    /// assert_eq!(5.0e20, a.distance(b));
+    /// ```
+    pub fn distance(&self, other: Vec3<T>) -> T {
+        let offset = (*self - other).abs();
+        let scale = offset.x.max(offset.y).max(offset.z);
+
+        if scale == T::zero() || scale.is_infinite() {
+            return scale;
+        }
+
+        (offset / scale).length() * scale
+    }
+
+    /// returns the point at `t` on the cubic Hermite curve from `p0` to
+    /// `p1`, leaving with the tangent `m0` and arriving with the tangent `m1`
+    ///
+    /// `t` runs from 0 at `p0` to 1 at `p1`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates the endpoints and their tangents
+    /// let p0 = Vec3::new(0.0, 0.0, 0.0);
+    /// let m0 = Vec3::new(1.0, 0.0, 0.0);
+    /// let p1 = Vec3::new(1.0, 1.0, 1.0);
+    /// let m1 = Vec3::new(0.0, 1.0, 0.0);
+    ///
+    /// assert_eq!(p0, Vec3::hermite(p0, m0, p1, m1, 0.0));
+    /// assert_eq!(p1, Vec3::hermite(p0, m0, p1, m1, 1.0));
+    /// ```
+    pub fn hermite(p0: Vec3<T>, m0: Vec3<T>, p1: Vec3<T>, m1: Vec3<T>, t: T) -> Vec3<T> {
+        let two = T::one() + T::one();
+        let three = two + T::one();
+
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        let h00 = two * t3 - three * t2 + T::one();
+        let h10 = t3 - two * t2 + t;
+        let h01 = three * t2 - two * t3;
+        let h11 = t3 - t2;
+
+        p0 * h00 + m0 * h10 + p1 * h01 + m1 * h11
+    }
+
+    /// returns a point distributed uniformly over the volume of a sphere
+    /// centered on the origin with the given radius
+    ///
+    /// `rng` is called for uniformly distributed values in [0, 1), so any
+    /// random number generator can be plugged in. the distance from the
+    /// center is the cube root of a uniform value, since picking it
+    /// uniformly would cluster points near the center
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // a tiny generator for the example, use a real one in practice
+    /// let mut seed = 12345_u32;
+    /// let mut rng = || {
+    ///     seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+    ///     seed as f64 / 4294967296.0
+    /// };
+    ///
+    /// let p = Vec3::random_in_sphere(&mut rng, 5.0);
+    ///
+    /// assert!(p.length() <= 5.0);
+    /// ```
+    pub fn random_in_sphere<R: FnMut() -> T>(rng: &mut R, radius: T) -> Vec3<T> where T: FloatConst {
+        let one = T::one();
+
+        // a uniform direction from a uniform height and angle around the axis
+        let z = (one + one) * rng() - one;
+        let angle = (T::PI() + T::PI()) * rng();
+        let ring = (one - z * z).max(T::zero()).sqrt();
+        let direction = Vec3::new(ring * angle.cos(), ring * angle.sin(), z);
+
+        direction * (radius * rng().cbrt())
+    }
+
+    /// returns the foot of the perpendicular from `point` onto the infinite
+    /// line through `a` and `b`
+    ///
+    /// unlike a segment, the line extends past both points. if `a` and `b`
+    /// are the same point there is no line, so `a` is returned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // a line through 2 points on the x axis
+    /// let a = Vec3::new(0.0, 0.0, 0.0);
+    /// let b = Vec3::new(1.0, 0.0, 0.0);
+    ///
+    /// // the foot lies well past b
+    /// let foot = Vec3::closest_point_on_line(Vec3::new(5.0, 3.0, -2.0), a, b);
+    ///
+    /// assert_eq!(Vec3::new(5.0, 0.0, 0.0), foot);
+    /// assert_eq!(a, Vec3::closest_point_on_line(Vec3::new(5.0, 3.0, -2.0), a, a));
+    /// ```
+    pub fn closest_point_on_line(point: Vec3<T>, a: Vec3<T>, b: Vec3<T>) -> Vec3<T> {
+        let direction = b - a;
+        let length_squared = direction.length_squared();
+
+        if length_squared == T::zero() {
+            return a;
+        }
+
+        a + direction * ((point - a).dot(direction) / length_squared)
+    }
+
+    /// returns an arbitrary unit vector perpendicular to the Vec3
+    fn any_orthogonal(&self) -> Vec3<T> {
+        let (x, y, z) = (self.x.abs(), self.y.abs(), self.z.abs());
+
+        let axis = if x <= y && x <= z {
+            Vec3::new(T::one(), T::zero(), T::zero())
+        } else if y <= z {
+            Vec3::new(T::zero(), T::one(), T::zero())
+        } else {
+            Vec3::new(T::zero(), T::zero(), T::one())
+        };
+
+        self.cross(axis).normalize()
+    }
+
+    /// returns the absolute difference between each component of 2 Vec3s
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates 2 new Vec3 objects
+    /// let v1 = Vec3::new(1.0, 5.0, -2.0);
+    /// let v2 = Vec3::new(4.0, 2.0, 2.0);
+    ///
+    /// // stores the per axis difference
+    /// let d = v1.abs_diff(v2);
+    ///
+    /// assert_eq!(Vec3::new(3.0, 3.0, 4.0), d);
+    /// ```
+    pub fn abs_diff(&self, other: Vec3<T>) -> Vec3<T> {
+        (*self - other).abs()
+    }
+
+    /// returns true if the length of the Vec3 is within `epsilon` of zero
+    ///
+    /// useful for guarding against the NaN produced by normalizing a zero
+    /// length vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a tiny Vec3
+    /// let v = Vec3::new(1e-9, 1e-9, 1e-9);
+    ///
+    /// assert!(v.approx_zero(1e-6));
+    /// assert!(!v.approx_zero(1e-12));
+    /// ```
+    pub fn approx_zero(&self, epsilon: T) -> bool {
+        self.length_squared() <= epsilon * epsilon
+    }
+
+    /// returns the total length of a path through the given points,
+    /// summing the distances between each consecutive pair
+    ///
+    /// an empty or single point slice has a length of zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a path of 3 points spaced 1 apart
+    /// let path = [Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, 1.0, 1.0), Vec3::new(0.0, 2.0, 1.0)];
+    ///
+    /// assert_eq!(2.0, Vec3::polyline_length(&path));
+    /// assert_eq!(0.0, Vec3::polyline_length(&[Vec3::new(4.0, 2.0, 1.0)]));
+    /// ```
+    pub fn polyline_length(points: &[Vec3<T>]) -> T {
+        points
+            .windows(2)
+            .fold(T::zero(), |total, pair| total + (pair[1] - pair[0]).length())
+    }
+
+    /// returns the Vec3 with `e` raised to the power of each component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(0.5_f64, 3.0, 42.0);
+    ///
+    /// // takes it out of log space and back again
+    /// let r = v.exp().ln();
+    ///
+    /// assert!((r - v).all(|c| c.abs() < 1e-12));
+    /// ```
+    pub fn exp(&self) -> Vec3<T> {
+        Vec3::new(self.x.exp(), self.y.exp(), self.z.exp())
+    }
+
+    /// returns the Vec3 with the natural logarithm of each component
+    ///
+    /// a zero component gives negative infinity and a negative one gives NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(0.5_f64, 3.0, 42.0);
+    ///
+    /// // takes it into log space and back again
+    /// let r = v.ln().exp();
+    ///
+    /// assert!((r - v).all(|c| c.abs() < 1e-12));
+    /// ```
+    pub fn ln(&self) -> Vec3<T> {
+        Vec3::new(self.x.ln(), self.y.ln(), self.z.ln())
+    }
+
+    /// returns the Vec3 with the sine of each component, in radians
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    /// use std::f64::consts::{FRAC_PI_2, PI};
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(0.0, FRAC_PI_2, PI);
+    ///
+    /// // takes the sine of each component
+    /// let s = v.sin();
+    ///
+    /// assert!((s - Vec3::new(0.0, 1.0, 0.0)).all(|c| c.abs() < 1e-12));
+    /// ```
+    pub fn sin(&self) -> Vec3<T> {
+        Vec3::new(self.x.sin(), self.y.sin(), self.z.sin())
+    }
+
+    /// returns the Vec3 with the cosine of each component, in radians
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    /// use std::f64::consts::{FRAC_PI_2, PI};
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(0.0, FRAC_PI_2, PI);
+    ///
+    /// // takes the cosine of each component
+    /// let c = v.cos();
+    ///
+    /// assert!((c - Vec3::new(1.0, 0.0, -1.0)).all(|c| c.abs() < 1e-12));
+    /// ```
+    pub fn cos(&self) -> Vec3<T> {
+        Vec3::new(self.x.cos(), self.y.cos(), self.z.cos())
+    }
+
+    /// returns the dot product of 2 3D vectors, accumulated with fused
+    /// multiply-adds to reduce the rounding error of the sum
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // the exact dot product of these is 2^-60
+    /// let a = 1.0 + 2f64.powi(-30);
+    /// let v1 = Vec3::new(a, 1.0, 0.0);
+    /// let v2 = Vec3::new(a, -(1.0 + 2f64.powi(-29)), 7.0);
+    ///
+    /// // the naive dot product rounds it away entirely
+    /// assert_eq!(0.0, v1.dot(v2));
+    /// assert_eq!(2f64.powi(-60), v1.dot_fma(v2));
+    /// ```
+    pub fn dot_fma(&self, other: Vec3<T>) -> T {
+        self.x.mul_add(other.x, self.y.mul_add(other.y, self.z * other.z))
+    }
+
+    /// returns the unsigned angle between 2 3D vectors, in radians
+    /// within [0, π]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// // creates 2 new Vec3 objects
+    /// let v1 = Vec3::new(2.0, 0.0, 0.0);
+    /// let v2 = Vec3::new(0.0, 0.0, -5.0);
+    ///
+    /// assert_eq!(FRAC_PI_2, v1.angle_between(v2));
+    /// ```
+    pub fn angle_between(&self, other: Vec3<T>) -> T {
+        let cos = self.dot(other) / (self.length() * other.length());
+
+        cos.max(-T::one()).min(T::one()).acos()
+    }
+
+    /// returns the unsigned angle between 2 3D vectors, in radians
+    /// within [0, π]
+    ///
+    /// computed as `atan2(|self × other|, self · other)`, which keeps full
+    /// precision near 0 and π where the `acos` in `angle_between` doesn't
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates 2 nearly parallel Vec3 objects
+    /// let v1 = Vec3::new(1.0, 0.0, 0.0);
+    /// let v2 = Vec3::new(1.0, 1e-10, 0.0);
+    ///
+    /// // acos rounds the tiny angle away
+    /// assert_eq!(0.0, v1.angle_between(v2));
+    /// assert_eq!(1e-10, v1.angle_between_stable(v2));
+    /// ```
+    pub fn angle_between_stable(&self, other: Vec3<T>) -> T {
+        self.cross(other).length().atan2(self.dot(other))
+    }
+
+    /// returns the unsigned angle between 2 3D vectors, in degrees
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates 2 new Vec3 objects
+    /// let v1 = Vec3::new(2.0, 0.0, 0.0);
+    /// let v2 = Vec3::new(0.0, 0.0, -5.0);
+    ///
+    /// assert_eq!(90.0, v1.angle_between_degrees(v2));
+    /// ```
+    pub fn angle_between_degrees(&self, other: Vec3<T>) -> T {
+        self.angle_between(other).to_degrees()
+    }
+
+    /// returns the Vec3 rotated by `angle` radians around a unit `axis`,
+    /// using Rodrigues' rotation formula
+    fn rotate_around_axis(&self, axis: Vec3<T>, angle: T) -> Vec3<T> {
+        let (sin, cos) = angle.sin_cos();
+
+        *self * cos + axis.cross(*self) * sin + axis * (axis.dot(*self) * (T::one() - cos))
+    }
+
+    /// returns the point scaled component-wise by `factor` around `pivot`
+    /// instead of the origin
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a point and a pivot to scale it around
+    /// let v = Vec3::new(3.0, 3.0, 3.0);
+    /// let pivot = Vec3::new(1.0, 1.0, 1.0);
+    ///
+    /// let s = v.scale_around(pivot, Vec3::new(2.0, 2.0, 0.5));
+    ///
+    /// assert_eq!(Vec3::new(5.0, 5.0, 2.0), s);
+    /// ```
+    pub fn scale_around(&self, pivot: Vec3<T>, factor: Vec3<T>) -> Vec3<T> {
+        (*self - pivot) * factor + pivot
+    }
+
+    /// returns how many components of the Vec3 are not zero
+    ///
+    /// NaN components are not equal to zero, so they are counted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(0.0, 2.0, -1.0);
+    ///
+    /// assert_eq!(2, v.count_nonzero());
+    /// ```
+    pub fn count_nonzero(&self) -> usize {
+        self.fold(0, |count, c| if c != T::zero() { count + 1 } else { count })
+    }
+
+    /// returns how many components of the Vec3 are NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a Vec3 that has blown up
+    /// let v = Vec3::new(1.0, f64::NAN, 3.0);
+    ///
+    /// assert_eq!(1, v.count_nan());
+    /// ```
+    pub fn count_nan(&self) -> usize {
+        self.fold(0, |count, c| if c.is_nan() { count + 1 } else { count })
+    }
+
+    /// returns the Vec3 with each component rounded to `decimals` decimal
+    /// places
+    ///
+    /// each component is scaled by 10^decimals before rounding, and any
+    /// component whose scaled value overflows to infinity is returned as it
+    /// is, which includes every component once 10^decimals itself overflows
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a noisy Vec3
+    /// let v = Vec3::new(1.23456, 2.0, -0.30000000000000004);
+    ///
+    /// assert_eq!(Vec3::new(1.23, 2.0, -0.3), v.round_to(2));
+    /// ```
+    pub fn round_to(&self, decimals: u32) -> Vec3<T> {
+        let exponent = i32::try_from(decimals).unwrap_or(i32::MAX);
+        let factor = T::from(10).unwrap().powi(exponent);
+
+        let round = |c: T| {
+            let scaled = c * factor;
+
+            if scaled.is_finite() {
+                scaled.round() / factor
+            } else {
+                c
+            }
+        };
+
+        Vec3::new(round(self.x), round(self.y), round(self.z))
+    }
+
+    /// returns true if every component of the 2 Vec3s is within `epsilon`
+    /// of the other
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates 2 nearly equal Vec3 objects
+    /// let v1 = Vec3::new(1.0, 2.0, 3.0);
+    /// let v2 = Vec3::new(1.0 + 1e-7, 2.0, 3.0 - 1e-7);
+    ///
+    /// assert!(v1.approx_eq(v2, 1e-6));
+    /// assert!(!v1.approx_eq(v2, 1e-8));
+    /// ```
+    pub fn approx_eq(&self, other: Vec3<T>, epsilon: T) -> bool {
+        (self.x - other.x).abs() <= epsilon
+            && (self.y - other.y).abs() <= epsilon
+            && (self.z - other.z).abs() <= epsilon
+    }
+
+    /// returns the rejection of the Vec3 from `other`, the part of it
+    /// perpendicular to `other`, along with that part's length
+    ///
+    /// a zero length `other` gives NaN components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates 2 new Vec3 objects
+    /// let v = Vec3::new(3.0, 4.0, 12.0);
+    /// let axis = Vec3::new(2.0, 0.0, 0.0);
+    ///
+    /// let (rejection, length) = v.reject_with_magnitude(axis);
+    ///
+    /// assert_eq!(Vec3::new(0.0, 4.0, 12.0), rejection);
+    /// assert_eq!((16.0_f64 + 144.0).sqrt(), length);
+    /// ```
+    pub fn reject_with_magnitude(&self, other: Vec3<T>) -> (Vec3<T>, T) {
+        let rejection = *self - other * (self.dot(other) / other.length_squared());
+
+        (rejection, rejection.length())
+    }
+
+    /// returns the position after one step of a critically damped spring
+    /// pulling the Vec3 toward `target`, updating `velocity` in place
+    ///
+    /// the damping is derived from `stiffness` so the spring settles as fast
+    /// as possible without oscillating. the step is implicit, so it stays
+    /// stable even for large `dt` where an explicit Euler spring explodes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // starts at rest away from the target
+    /// let target = Vec3::new(10.0, 0.0, 0.0);
+    /// let mut position = Vec3::new(0.0, 0.0, 0.0);
+    /// let mut velocity = Vec3::new(0.0, 0.0, 0.0);
+    ///
+    /// for _ in 0..100 {
+    ///     position = position.spring(target, &mut velocity, 100.0, 0.1);
+    ///
+    ///     // never overshoots the target
+    ///     assert!(position.x() <= 10.0);
+    /// }
+    ///
+    /// assert!(position.approx_eq(target, 1e-9));
+    /// ```
+    pub fn spring(&self, target: Vec3<T>, velocity: &mut Vec3<T>, stiffness: T, dt: T) -> Vec3<T> {
+        let damping = (T::one() + T::one()) * stiffness.sqrt();
+        let denominator = T::one() + dt * damping + dt * dt * stiffness;
+
+        *velocity = (*velocity - (*self - target) * (dt * stiffness)) / denominator;
+
+        *self + *velocity * dt
+    }
+
+    /// returns `self * mul + add` per component, computed with a fused
+    /// multiply-add that rounds only once
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates 3 new Vec3 objects
+    /// let v = Vec3::new(2.0, 3.0, -1.0);
+    /// let mul = Vec3::new(4.0, 0.5, 2.0);
+    /// let add = Vec3::new(1.0, -1.0, 0.0);
+    ///
+    /// assert_eq!(v * mul + add, v.mul_add(mul, add));
+    /// assert_eq!(Vec3::new(9.0, 0.5, -2.0), v.mul_add(mul, add));
+    /// ```
+    pub fn mul_add(&self, mul: Vec3<T>, add: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.x.mul_add(mul.x, add.x),
+            self.y.mul_add(mul.y, add.y),
+            self.z.mul_add(mul.z, add.z),
+        )
+    }
+
+    /// returns the point a fraction `t` of the way from the Vec3 to `other`,
+    /// with `t` clamped to [0, 1] so the result never passes either end
+    ///
+    /// use `lerp_unclamped` to extrapolate beyond the 2 points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a start and an end point
+    /// let start = Vec3::new(0.0, 0.0, 0.0);
+    /// let end = Vec3::new(10.0, -4.0, 2.0);
+    ///
+    /// assert_eq!(end, start.lerp(end, 1.5));
+    /// assert_eq!(start, start.lerp(end, -0.5));
+    /// ```
+    pub fn lerp(&self, other: Vec3<T>, t: T) -> Vec3<T> {
+        self.lerp_unclamped(other, t.max(T::zero()).min(T::one()))
+    }
+
+    /// returns the point a fraction `t` of the way from the Vec3 to `other`,
+    /// without clamping `t`, so values outside [0, 1] extrapolate past the
+    /// ends
+    ///
+    /// use `lerp` to stay between the 2 points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a start and an end point
+    /// let start = Vec3::new(0.0, 0.0, 0.0);
+    /// let end = Vec3::new(10.0, -4.0, 2.0);
+    ///
+    /// assert_eq!(Vec3::new(15.0, -6.0, 3.0), start.lerp_unclamped(end, 1.5));
+    /// ```
+    pub fn lerp_unclamped(&self, other: Vec3<T>, t: T) -> Vec3<T> {
+        *self + (other - *self) * t
+    }
+
+    /// returns the Vec3 blended toward `other` by a separate fraction along
+    /// each axis, taken from the matching component of `weight`
+    ///
+    /// like `lerp_unclamped`, the weights are not clamped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates 2 Vec3s to blend between
+    /// let a = Vec3::new(1.0, 2.0, 3.0);
+    /// let b = Vec3::new(5.0, 6.0, 7.0);
+    ///
+    /// // takes x from a, y from b and z from halfway between
+    /// assert_eq!(Vec3::new(1.0, 6.0, 5.0), a.mix(b, Vec3::new(0.0, 1.0, 0.5)));
+    /// ```
+    pub fn mix(&self, other: Vec3<T>, weight: Vec3<T>) -> Vec3<T> {
+        let keep = Vec3::new(T::one() - weight.x, T::one() - weight.y, T::one() - weight.z);
+
+        *self * keep + other * weight
+    }
+
+    /// moves the Vec3 in place toward `other` by the fraction `t`, where 0
+    /// leaves it unchanged and 1 moves it all the way
+    ///
+    /// like `lerp_unclamped`, `t` is not clamped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a position and a target
+    /// let mut v = Vec3::new(0.0, 0.0, 0.0);
+    /// let target = Vec3::new(10.0, -4.0, 2.0);
+    ///
+    /// // moves half of the way there
+    /// v.lerp_assign(target, 0.5);
+    /// assert_eq!(Vec3::new(5.0, -2.0, 1.0), v);
+    ///
+    /// // easing in every frame converges on the target
+    /// for _ in 0..100 {
+    ///     v.lerp_assign(target, 0.5);
+    /// }
+    ///
+    /// assert!(v.approx_eq(target, 1e-12));
+    /// ```
+    pub fn lerp_assign(&mut self, other: Vec3<T>, t: T) {
+        *self = self.lerp_unclamped(other, t);
+    }
+
+    /// returns the Vec3 scaled so its length lies within [min, max], only
+    /// computing the length once
+    ///
+    /// if `min` is greater than `max` then `max` wins. a zero length Vec3
+    /// has no direction to scale along, so it is returned unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// assert_eq!(Vec3::new(0.0, 6.0, 8.0), Vec3::new(0.0, 30.0, 40.0).clamp_length(2.5, 10.0));
+    /// assert_eq!(Vec3::new(0.0, 1.5, 2.0), Vec3::new(0.0, 0.3, 0.4).clamp_length(2.5, 10.0));
+    /// assert_eq!(Vec3::new(0.0, 3.0, 4.0), Vec3::new(0.0, 3.0, 4.0).clamp_length(2.5, 10.0));
+    /// ```
+    pub fn clamp_length(&self, min: T, max: T) -> Vec3<T> {
+        let length = self.length();
+
+        if length == T::zero() {
+            return *self;
+        }
+
+        let clamped = length.max(min).min(max);
+
+        if clamped == length {
+            *self
+        } else {
+            *self * (clamped / length)
+        }
+    }
+
+    /// returns the Vec3 with each component mapped from the range
+    /// [from_min, from_max] onto the range [to_min, to_max]
+    ///
+    /// inputs outside of the source range extrapolate past the target range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // maps 0 to 1 on the first axis and 0 to 10 on the rest onto 0 to 100
+    /// let from_min = Vec3::new(0.0, 0.0, 0.0);
+    /// let from_max = Vec3::new(1.0, 10.0, 10.0);
+    /// let to_min = Vec3::new(0.0, 0.0, 0.0);
+    /// let to_max = Vec3::new(100.0, 100.0, 100.0);
+    ///
+    /// let v = Vec3::new(0.5, 20.0, -5.0);
+    ///
+    /// assert_eq!(Vec3::new(50.0, 200.0, -50.0), v.remap(from_min, from_max, to_min, to_max));
+    /// ```
+    pub fn remap(&self, from_min: Vec3<T>, from_max: Vec3<T>, to_min: Vec3<T>, to_max: Vec3<T>) -> Vec3<T> {
+        let t = (*self - from_min) / (from_max - from_min);
+
+        to_min + (to_max - to_min) * t
+    }
+
+    /// returns the Vec3 with each component clamped into the range
+    /// [from_min, from_max] and then mapped onto the range [to_min, to_max]
+    ///
+    /// unlike `remap`, inputs outside of the source range never leave the
+    /// target range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // maps 0 to 1 on the first axis and 0 to 10 on the rest onto 0 to 100
+    /// let from_min = Vec3::new(0.0, 0.0, 0.0);
+    /// let from_max = Vec3::new(1.0, 10.0, 10.0);
+    /// let to_min = Vec3::new(0.0, 0.0, 0.0);
+    /// let to_max = Vec3::new(100.0, 100.0, 100.0);
+    ///
+    /// let v = Vec3::new(0.5, 20.0, -5.0);
+    ///
+    /// assert_eq!(Vec3::new(50.0, 100.0, 0.0), v.remap_clamped(from_min, from_max, to_min, to_max));
+    /// ```
+    pub fn remap_clamped(&self, from_min: Vec3<T>, from_max: Vec3<T>, to_min: Vec3<T>, to_max: Vec3<T>) -> Vec3<T> {
+        let t = ((*self - from_min) / (from_max - from_min)).clamp_scalar(T::zero(), T::one());
+
+        to_min + (to_max - to_min) * t
+    }
+
+    /// returns the Vec3 with every component clamped into the range [lo, hi]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a new Vec3
+    /// let v = Vec3::new(-5.0, 5.0, 0.5);
+    ///
+    /// assert_eq!(Vec3::new(0.0, 1.0, 0.5), v.clamp_scalar(0.0, 1.0));
+    /// ```
+    pub fn clamp_scalar(&self, lo: T, hi: T) -> Vec3<T> {
+        Vec3::new(self.x.max(lo).min(hi), self.y.max(lo).min(hi), self.z.max(lo).min(hi))
+    }
+
+    /// compares the lengths of 2 Vec3s, for sorting vectors by magnitude
+    ///
+    /// squared lengths are compared, so no square roots are taken. NaN
+    /// lengths are treated as equal to each other and greater than every
+    /// other length, so the ordering stays total
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates some vectors of different lengths
+    /// let mut v = [Vec3::new(3.0, 4.0, 0.0), Vec3::new(f64::NAN, 0.0, 0.0), Vec3::new(0.0, -1.0, 0.0), Vec3::new(0.0, 0.0, 2.0)];
+    ///
+    /// v.sort_by(|a, b| a.cmp_by_length(b));
+    ///
+    /// assert_eq!(Vec3::new(0.0, -1.0, 0.0), v[0]);
+    /// assert_eq!(Vec3::new(0.0, 0.0, 2.0), v[1]);
+    /// assert_eq!(Vec3::new(3.0, 4.0, 0.0), v[2]);
+    /// assert!(v[3].x().is_nan());
+    /// ```
+    pub fn cmp_by_length(&self, other: &Vec3<T>) -> Ordering {
+        let a = self.length_squared();
+        let b = other.length_squared();
+
+        match (a.is_nan(), b.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => a.partial_cmp(&b).unwrap(),
+        }
+    }
+
+    /// returns the velocity after hitting a surface with the unit normal
+    /// `normal`, handling the bounce and the sliding separately
+    ///
+    /// the part of the velocity along the normal is reversed and scaled by
+    /// `restitution`, and the part along the surface is scaled by
+    /// `1 - friction`. `normal` must be unit length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // a velocity hitting the floor at an angle
+    /// let v = Vec3::new(4.0, -2.0, 8.0);
+    /// let floor = Vec3::new(0.0, 1.0, 0.0);
+    ///
+    /// assert_eq!(Vec3::new(3.0, 1.0, 6.0), v.resolve_surface(floor, 0.5, 0.25));
+    ///
+    /// // full friction only keeps the bounce, no restitution only keeps the slide
+    /// assert_eq!(Vec3::new(0.0, 2.0, 0.0), v.resolve_surface(floor, 1.0, 1.0));
+    /// assert_eq!(Vec3::new(4.0, 0.0, 8.0), v.resolve_surface(floor, 0.0, 0.0));
+    /// ```
+    pub fn resolve_surface(&self, normal: Vec3<T>, restitution: T, friction: T) -> Vec3<T> {
+        let normal_part = normal * self.dot(normal);
+        let tangent_part = *self - normal_part;
+
+        tangent_part * (T::one() - friction) - normal_part * restitution
+    }
+
+    /// returns the Vec3 with each component raised to at least `s`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a mixed sign Vec3
+    /// let v = Vec3::new(-3.0, 2.0, 0.5);
+    ///
+    /// assert_eq!(Vec3::new(0.0, 2.0, 0.5), v.max_scalar(0.0));
+    /// ```
+    pub fn max_scalar(&self, s: T) -> Vec3<T> {
+        Vec3::new(self.x.max(s), self.y.max(s), self.z.max(s))
+    }
+
+    /// returns the Vec3 with each component lowered to at most `s`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a mixed sign Vec3
+    /// let v = Vec3::new(-3.0, 2.0, 0.5);
+    ///
+    /// assert_eq!(Vec3::new(-3.0, 0.0, 0.0), v.min_scalar(0.0));
+    /// ```
+    pub fn min_scalar(&self, s: T) -> Vec3<T> {
+        Vec3::new(self.x.min(s), self.y.min(s), self.z.min(s))
+    }
+
+    /// returns the Vec3 scaled by a different factor along each axis
+    ///
+    /// this is the same as multiplying 2 Vec3s with the `*` operator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a Vec3 and per axis scale factors
+    /// let v = Vec3::new(3.0, 4.0, 5.0);
+    /// let factors = Vec3::new(2.0, 0.5, -1.0);
+    ///
+    /// assert_eq!(Vec3::new(6.0, 2.0, -5.0), v.scale(factors));
+    /// assert_eq!(v * factors, v.scale(factors));
+    /// ```
+    pub fn scale(&self, factors: Vec3<T>) -> Vec3<T> {
+        *self * factors
+    }
+
+    /// returns the outer product of 2 3D vectors as a row-major 3x3 matrix,
+    /// where `matrix[i][j]` is `self[i] * other[j]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates 2 basis vectors
+    /// let x = Vec3::new(1.0, 0.0, 0.0);
+    /// let y = Vec3::new(0.0, 1.0, 0.0);
+    ///
+    /// let m = x.outer(y);
+    ///
+    /// assert_eq!([[0.0, 1.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]], m);
+    /// ```
+    pub fn outer(&self, other: Vec3<T>) -> [[T; 3]; 3] {
+        [
+            [self.x * other.x, self.x * other.y, self.x * other.z],
+            [self.y * other.x, self.y * other.y, self.y * other.z],
+            [self.z * other.x, self.z * other.y, self.z * other.z],
+        ]
+    }
+
+    /// returns true if the axis aligned boxes `min_a..max_a` and
+    /// `min_b..max_b` overlap on all 3 axes
+    ///
+    /// boxes that only touch along a face, edge or corner count as
+    /// overlapping
+    ///
+    /// # Examples
+    ///
     /// ```
     /// use vecs::Vec3;
-    /// 
-    /// // creates a new Vec3 called v1
-    /// let v1 = Vec3::new(1.0, 2.0, 3.0);
-    /// 
-    /// // creates a new Vec3 call v2
-    /// let v2 = Vec3::new(10.0, 20.0, 30.0);
+    ///
+    /// // creates a unit box at the origin
+    /// let (min, max) = (Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0));
+    ///
+    /// // overlapping, touching and disjoint boxes
+    /// assert!(Vec3::aabb_overlap(min, max, Vec3::new(0.5, 0.5, 0.5), Vec3::new(2.0, 2.0, 2.0)));
+    /// assert!(Vec3::aabb_overlap(min, max, Vec3::new(0.0, 0.0, 1.0), Vec3::new(1.0, 1.0, 2.0)));
+    /// assert!(!Vec3::aabb_overlap(min, max, Vec3::new(0.0, 0.0, 1.5), Vec3::new(1.0, 1.0, 2.0)));
     /// ```
-    pub fn new(x: T, y: T, z: T) -> Vec3<T> {
-        Vec3 { x, y, z }
+    pub fn aabb_overlap(min_a: Vec3<T>, max_a: Vec3<T>, min_b: Vec3<T>, max_b: Vec3<T>) -> bool {
+        min_a.x <= max_b.x
+            && min_b.x <= max_a.x
+            && min_a.y <= max_b.y
+            && min_b.y <= max_a.y
+            && min_a.z <= max_b.z
+            && min_b.z <= max_a.z
     }
 
-    /// returns the dot product of 2 3D vectors
-    /// 
+    /// returns true if `point` lies inside the axis aligned box `min..max`
+    ///
+    /// the test is inclusive, so points on a face, edge or corner of the
+    /// box count as contained
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecs::Vec3;
-    /// 
-    /// // creates 2 new Vec3 objects
-    /// let v1 = Vec3::new(1.0, 2.0, 3.0);
-    /// let v2 = Vec3::new(1.0, 2.0, 3.0);
-    /// 
-    /// // stores their dot product
-    /// let d = v1.dot(v2);
-    /// 
-    /// assert_eq!(14.0, d);
+    ///
+    /// // creates a unit box at the origin
+    /// let (min, max) = (Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0));
+    ///
+    /// // a point inside, on a face and outside the box
+    /// assert!(Vec3::aabb_contains(min, max, Vec3::new(0.5, 0.5, 0.5)));
+    /// assert!(Vec3::aabb_contains(min, max, Vec3::new(0.5, 0.5, 1.0)));
+    /// assert!(!Vec3::aabb_contains(min, max, Vec3::new(0.5, 0.5, 1.5)));
     /// ```
-    pub fn dot(&self, other: Vec3<T>) -> T {
-        self.x * other.x + self.y * other.y + self.z * other.z
+    pub fn aabb_contains(min: Vec3<T>, max: Vec3<T>, point: Vec3<T>) -> bool {
+        min.x <= point.x
+            && point.x <= max.x
+            && min.y <= point.y
+            && point.y <= max.y
+            && min.z <= point.z
+            && point.z <= max.z
     }
 
-    /// returns the cross product of 2 3D vectors
-    /// 
+    /// grows the axis aligned box `min..max` so that it contains `point`
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecs::Vec3;
-    /// 
-    /// // creates 2 new Vec3 objects
-    /// let v1 = Vec3::new(3.0, 2.0, 1.0);
-    /// let v2 = Vec3::new(1.0, 2.0, 3.0);
-    /// 
-    /// // stores their cross product
-    /// let v3 = v1.cross(v2);
-    /// 
-    /// assert_eq!(Vec3::new(4.0, -8.0, 4.0), v3);
+    ///
+    /// // starts the bounds at a single point
+    /// let mut min = Vec3::new(1.0, 1.0, 1.0);
+    /// let mut max = min;
+    ///
+    /// Vec3::aabb_expand(&mut min, &mut max, Vec3::new(-2.0, 3.0, 1.0));
+    ///
+    /// assert_eq!(Vec3::new(-2.0, 1.0, 1.0), min);
+    /// assert_eq!(Vec3::new(1.0, 3.0, 1.0), max);
     /// ```
-    pub fn cross(&self, other: Vec3<T>) -> Vec3<T> {
-        let x = (self.y * other.z) - (self.z * other.y);
-        let y = (self.x * other.z) - (self.z * other.x);
-        let z = (self.x * other.y) - (self.y * other.x);
-
-        Vec3::new(x, -y, z)
+    pub fn aabb_expand(min: &mut Vec3<T>, max: &mut Vec3<T>, point: Vec3<T>) {
+        *min = Vec3::new(min.x.min(point.x), min.y.min(point.y), min.z.min(point.z));
+        *max = Vec3::new(max.x.max(point.x), max.y.max(point.y), max.z.max(point.z));
     }
 
-    /// returns the length of the Vec3
-    /// 
+    /// returns the index of the largest component of the Vec3
+    ///
+    /// ties go to the lowest index, NaN components are skipped, and 0 is
+    /// returned if every component is NaN
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecs::Vec3;
-    /// 
+    ///
     /// // creates a new Vec3
-    /// let v = Vec3::new(10.0, 10.0, 10.0);
-    /// 
-    /// // gets its length
-    /// let len = v.length();
-    /// 
-    /// assert_eq!(f64::sqrt(300.0), len);
+    /// let v = Vec3::new(1.0, 3.0, 2.0);
+    ///
+    /// assert_eq!(1, v.max_axis());
+    /// assert_eq!(0, Vec3::new(2.0, 2.0, 2.0).max_axis());
     /// ```
-    pub fn length(&self) -> T {
-        (self.x.powi(2) + self.y.powi(2) + self.z.powi(2)).sqrt()
+    pub fn max_axis(&self) -> usize {
+        self.enumerate_components()
+            .filter(|&(_, c)| !c.is_nan())
+            .fold(None, |best, (i, c)| match best {
+                Some((_, b)) if c <= b => best,
+                _ => Some((i, c)),
+            })
+            .map_or(0, |(i, _)| i)
     }
 
-    /// returns the squared length of the Vec3
-    /// 
+    /// returns the index of the smallest component of the Vec3
+    ///
+    /// ties go to the lowest index, NaN components are skipped, and 0 is
+    /// returned if every component is NaN
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecs::Vec3;
-    /// 
+    ///
     /// // creates a new Vec3
-    /// let v = Vec3::new(10.0, 10.0, 10.0);
-    /// 
-    /// // gets its length
-    /// let len = v.length_squared();
-    /// 
-    /// assert_eq!(300.0, len);
+    /// let v = Vec3::new(1.0, 3.0, 2.0);
+    ///
+    /// assert_eq!(0, v.min_axis());
+    /// assert_eq!(0, Vec3::new(2.0, 2.0, 2.0).min_axis());
     /// ```
-    pub fn length_squared(&self) -> T {
-        self.x.powi(2) + self.y.powi(2) + self.z.powi(2)
+    pub fn min_axis(&self) -> usize {
+        self.enumerate_components()
+            .filter(|&(_, c)| !c.is_nan())
+            .fold(None, |best, (i, c)| match best {
+                Some((_, b)) if c >= b => best,
+                _ => Some((i, c)),
+            })
+            .map_or(0, |(i, _)| i)
     }
 
-    /// returns the normalized the Vec3
-    /// 
+    /// returns the Vec3 with the magnitude of each component limited to the
+    /// matching component of `max`, keeping its sign
+    ///
+    /// the components of `max` are expected to be non-negative
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecs::Vec3;
-    /// 
-    /// // creates a new Vec3
-    /// let v = Vec3::new(100.0, 0.0, 0.0);
-    /// 
-    /// // stores the normalized Vec3
-    /// let n = v.normalize();
-    /// 
-    /// assert_eq!(Vec3::new(1.0, 0.0, 0.0), n);
+    ///
+    /// // creates a velocity and its per axis limits
+    /// let velocity = Vec3::new(-5.0, 0.5, 3.0);
+    /// let limits = Vec3::new(2.0, 1.0, 1.0);
+    ///
+    /// assert_eq!(Vec3::new(-2.0, 0.5, 1.0), velocity.clamp_abs(limits));
     /// ```
-    pub fn normalize(&self) -> Vec3<T> {
-        let length = self.length();
+    pub fn clamp_abs(&self, max: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.x.max(-max.x).min(max.x),
+            self.y.max(-max.y).min(max.y),
+            self.z.max(-max.z).min(max.z),
+        )
+    }
 
-        *self / length
+    /// returns the Vec3 with any motion into a surface with the unit
+    /// `normal` removed, leaving the motion along and away from it
+    ///
+    /// unlike projecting onto the surface, motion away from it is kept, so a
+    /// character sliding along a wall can still step off of it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a floor facing up
+    /// let normal = Vec3::new(0.0, 1.0, 0.0);
+    ///
+    /// // moving into the floor slides along it
+    /// assert_eq!(Vec3::new(3.0, 0.0, 1.0), Vec3::new(3.0, -2.0, 1.0).slide(normal));
+    ///
+    /// // moving away from the floor is untouched
+    /// assert_eq!(Vec3::new(3.0, 2.0, 1.0), Vec3::new(3.0, 2.0, 1.0).slide(normal));
+    /// ```
+    pub fn slide(&self, normal: Vec3<T>) -> Vec3<T> {
+        *self - normal * self.dot(normal).min(T::zero())
     }
-    
-    /// returns the absolute version of the Vec3
-    /// 
+
+    /// returns the Vec3 reflected off a surface with the unit `normal`,
+    /// along with the dot product of the Vec3 and the normal
+    ///
+    /// for a unit Vec3 the dot product is the cosine of the angle between it
+    /// and the normal, which is negative for a Vec3 heading into the surface
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecs::Vec3;
-    /// 
-    /// // creates a new Vec3
-    /// let v = Vec3::new(-12.0, 15.0, -9.0);
-    /// 
-    /// // stores it's absolute variant
-    /// let a = v.abs();
-    /// 
-    /// assert_eq!(Vec3::new(12.0, 15.0, 9.0), a);
+    ///
+    /// // creates a ray heading down into a floor at 45 degrees
+    /// let ray = Vec3::new(1.0, -1.0, 0.0).normalize();
+    /// let normal = Vec3::new(0.0, 1.0, 0.0);
+    ///
+    /// let (reflected, cos) = ray.reflect_with_cosine(normal);
+    ///
+    /// assert_eq!(Vec3::new(ray.x(), -ray.y(), 0.0), reflected);
+    /// assert_eq!(ray.y(), cos);
     /// ```
-    pub fn abs(&self) -> Vec3<T> {
-        Vec3::new(self.x.abs(), self.y.abs(), self.z.abs())
+    pub fn reflect_with_cosine(&self, normal: Vec3<T>) -> (Vec3<T>, T) {
+        let cos = self.dot(normal);
+
+        (*self - normal * (cos + cos), cos)
     }
 
-    /// gets the x value of the Vec3
+    /// returns, for each component, whichever of the 2 Vec3s has the larger
+    /// absolute value, keeping its sign
+    ///
+    /// ties keep the component of the Vec3 itself
     ///
     /// # Examples
     ///
     /// ```
     /// use vecs::Vec3;
     ///
-    /// // creates a new Vec3
-    /// let v = Vec3::new(15.0, 7.0, 2.0);
+    /// // creates a held peak and a new sample
+    /// let peak = Vec3::new(-5.0, 1.0, 2.0);
+    /// let sample = Vec3::new(2.0, -3.0, -2.0);
     ///
-    /// assert_eq!(15.0, v.x());
+    /// assert_eq!(Vec3::new(-5.0, -3.0, 2.0), peak.max_abs(sample));
     /// ```
-    pub fn x(&self) -> T {
-        self.x
+    pub fn max_abs(&self, other: Vec3<T>) -> Vec3<T> {
+        let pick = |a: T, b: T| if b.abs() > a.abs() { b } else { a };
+
+        Vec3::new(pick(self.x, other.x), pick(self.y, other.y), pick(self.z, other.z))
     }
 
-    /// gets the y value of the Vec3
+    /// returns the point expressed in the local frame at `origin` with the
+    /// given axes
+    ///
+    /// the axes are expected to be orthonormal, each local coordinate is the
+    /// dot product of the offset from `origin` with that axis
     ///
     /// # Examples
     ///
     /// ```
     /// use vecs::Vec3;
     ///
-    /// // creates a new Vec3
-    /// let v = Vec3::new(15.0, 7.0, 2.0);
+    /// // creates a frame at (1, 0, 0) turned a quarter around the z axis
+    /// let origin = Vec3::new(1.0, 0.0, 0.0);
+    /// let x_axis = Vec3::new(0.0, 1.0, 0.0);
+    /// let y_axis = Vec3::new(-1.0, 0.0, 0.0);
+    /// let z_axis = Vec3::new(0.0, 0.0, 1.0);
     ///
-    /// assert_eq!(7.0, v.y());
+    /// let p = Vec3::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(Vec3::new(2.0, 0.0, 3.0), p.to_local(origin, x_axis, y_axis, z_axis));
     /// ```
-    pub fn y(&self) -> T {
-        self.y
+    pub fn to_local(&self, origin: Vec3<T>, x_axis: Vec3<T>, y_axis: Vec3<T>, z_axis: Vec3<T>) -> Vec3<T> {
+        let offset = *self - origin;
+
+        Vec3::new(offset.dot(x_axis), offset.dot(y_axis), offset.dot(z_axis))
     }
 
-    /// gets the z value of the Vec3
+    /// returns the world position of the Vec3 given as coordinates in the
+    /// local frame at `origin` with the given axes
+    ///
+    /// this undoes `to_local` when the axes are orthonormal
     ///
     /// # Examples
     ///
     /// ```
     /// use vecs::Vec3;
     ///
-    /// // creates a new Vec3
-    /// let v = Vec3::new(15.0, 7.0, 2.0);
+    /// // creates a frame at (1, 0, 0) turned a quarter around the z axis
+    /// let origin = Vec3::new(1.0, 0.0, 0.0);
+    /// let x_axis = Vec3::new(0.0, 1.0, 0.0);
+    /// let y_axis = Vec3::new(-1.0, 0.0, 0.0);
+    /// let z_axis = Vec3::new(0.0, 0.0, 1.0);
     ///
-    /// assert_eq!(2.0, v.z());
+    /// let local = Vec3::new(2.0, 0.0, 3.0);
+    ///
+    /// assert_eq!(Vec3::new(1.0, 2.0, 3.0), local.from_local(origin, x_axis, y_axis, z_axis));
     /// ```
-    pub fn z(&self) -> T {
-        self.z
+    pub fn from_local(&self, origin: Vec3<T>, x_axis: Vec3<T>, y_axis: Vec3<T>, z_axis: Vec3<T>) -> Vec3<T> {
+        origin + x_axis * self.x + y_axis * self.y + z_axis * self.z
     }
+}
 
-    /// sets the x, y, and z values of the Vec3
-    /// 
+impl Vec3<f32> {
+    /// returns an approximately normalized Vec3 using a fast inverse square
+    /// root estimate refined by a single Newton step
+    ///
+    /// the length of the result is within about 0.2% of 1, giving up some
+    /// accuracy compared to `normalize` for speed, and a zero vector stays
+    /// zero rather than becoming NaN
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecs::Vec3;
-    /// 
+    ///
     /// // creates a new Vec3
-    /// let mut v = Vec3::new(9.0, 7.0, 1.0);
-    /// 
-    /// // gives v new values
-    /// v.set(5.0, 0.0, 8.0);
-    /// 
-    /// assert_eq!(Vec3::new(5.0, 0.0, 8.0), v);
+    /// let v = Vec3::new(2.0_f32, 3.0, 6.0);
+    ///
+    /// assert!((v.normalize_fast() - v.normalize()).length() < 1e-2);
     /// ```
-    pub fn set(&mut self, x: T, y: T, z: T) {
-        self.x = x;
-        self.y = y;
-        self.z = z;
+    pub fn normalize_fast(&self) -> Vec3<f32> {
+        let length_squared = self.length_squared();
+
+        let estimate = f32::from_bits(0x5f3759df - (length_squared.to_bits() >> 1));
+        let inv_length = estimate * (1.5 - 0.5 * length_squared * estimate * estimate);
+
+        *self * inv_length
+    }
+
+    /// returns the Vec3 as 4 floats with a zero `w` appended, matching the
+    /// 16 byte alignment std140 and std430 buffers expect for a vec3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecs::Vec3;
+    ///
+    /// // creates a light position to upload
+    /// let v = Vec3::new(1.0_f32, 2.0, 3.0);
+    ///
+    /// assert_eq!([1.0, 2.0, 3.0, 0.0], v.to_padded_array());
+    /// ```
+    pub fn to_padded_array(&self) -> [f32; 4] {
+        [self.x, self.y, self.z, 0.0]
     }
 }
 
@@ -235,6 +2426,30 @@ impl<T: Float> Sub for Vec3<T> {
     }
 }
 
+/// adds the scalar to every component of the Vec3
+///
+/// only `Vec3 + T` is provided, writing the scalar on the left
+/// (`T + Vec3`) would need a separate impl for each of f32 and f64
+impl<T: Float> Add<T> for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn add(self, rhs: T) -> Self::Output {
+        Self { x: self.x + rhs, y: self.y + rhs, z: self.z + rhs }
+    }
+}
+
+/// subtracts the scalar from every component of the Vec3
+///
+/// only `Vec3 - T` is provided, writing the scalar on the left
+/// (`T - Vec3`) would need a separate impl for each of f32 and f64
+impl<T: Float> Sub<T> for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn sub(self, rhs: T) -> Self::Output {
+        Self { x: self.x - rhs, y: self.y - rhs, z: self.z - rhs }
+    }
+}
+
 impl<T: Float + AddAssign> AddAssign for Vec3<T> {
     fn add_assign(&mut self, rhs: Self) {
         self.x += rhs.x;
@@ -260,14 +2475,6 @@ impl<T: Float + Mul + Copy> Mul<T> for Vec3<T> {
 
 }
 
-impl<T: Float> Mul<Vec3<T>> for f32 where f32: Mul<T, Output = T> {
-    type Output = Vec3<T>;
-
-    fn mul(self, rhs: Vec3<T>) -> Self::Output {
-        Vec3::new(self * rhs.x, self * rhs.y, self * rhs.z)
-    }
-}
-
 impl<T: Float + Mul + Copy> Mul<Vec3<T>> for Vec3<T> {
     type Output = Vec3<T>;
     
@@ -286,14 +2493,6 @@ impl<T: Float + Div + Copy> Div<T> for Vec3<T> {
 
 }
 
-impl<T: Float> Div<Vec3<T>> for f32 where f32: Div<T, Output = T> {
-    type Output = Vec3<T>;
-
-    fn div(self, rhs: Vec3<T>) -> Self::Output {
-        Vec3::new(self / rhs.x, self / rhs.y, self / rhs.z)
-    }
-}
-
 impl<T: Float + Div + Copy> Div<Vec3<T>> for Vec3<T> {
     type Output = Vec3<T>;
     
@@ -303,6 +2502,30 @@ impl<T: Float + Div + Copy> Div<Vec3<T>> for Vec3<T> {
 
 }
 
+/// implements multiplying and dividing a scalar on the left by a Vec3 of
+/// the same float type, so `2.0 * v` works as well as `v * 2.0`
+macro_rules! impl_scalar_lhs {
+    ($($t:ty),*) => {$(
+        impl Mul<Vec3<$t>> for $t {
+            type Output = Vec3<$t>;
+
+            fn mul(self, rhs: Vec3<$t>) -> Self::Output {
+                Vec3::new(self * rhs.x, self * rhs.y, self * rhs.z)
+            }
+        }
+
+        impl Div<Vec3<$t>> for $t {
+            type Output = Vec3<$t>;
+
+            fn div(self, rhs: Vec3<$t>) -> Self::Output {
+                Vec3::new(self / rhs.x, self / rhs.y, self / rhs.z)
+            }
+        }
+    )*};
+}
+
+impl_scalar_lhs!(f32, f64);
+
 impl<T: Float> Neg for Vec3<T> {
     type Output = Vec3<T>;
 
@@ -311,7 +2534,89 @@ impl<T: Float> Neg for Vec3<T> {
     }
 }
 
-impl<T: Float + fmt::Display> fmt::Display for Vec3<T> {
+impl<T: Copy> AsRef<[T]> for Vec3<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: Copy> AsMut<[T]> for Vec3<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+/// builds a Vec3 from the first 3 items of an iterator
+///
+/// any items after the first 3 are ignored
+///
+/// # Panics
+///
+/// panics if the iterator yields fewer than 3 items
+impl<T> FromIterator<T> for Vec3<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let mut next = || iter.next().expect("not enough items to build a Vec3");
+        let x = next();
+        let y = next();
+        let z = next();
+
+        Vec3 { x, y, z }
+    }
+}
+
+impl<T: Eq> Eq for Vec3<T> {}
+
+/// orders Vec3s lexicographically, comparing x, then y, then z
+///
+/// this has no geometric meaning, it only exists so vectors of integers
+/// can be sorted deterministically or used as keys in ordered collections
+impl<T: Ord> Ord for Vec3<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.x.cmp(&other.x)
+            .then_with(|| self.y.cmp(&other.y))
+            .then_with(|| self.z.cmp(&other.z))
+    }
+}
+
+impl<T: Ord> PartialOrd for Vec3<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// the alternate form (`{:#?}`) prints one component per line with the
+/// values right aligned, the regular form matches the derived output
+impl<T: fmt::Debug> fmt::Debug for Vec3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !f.alternate() {
+            return f.debug_struct("Vec3")
+                .field("x", &self.x)
+                .field("y", &self.y)
+                .field("z", &self.z)
+                .finish();
+        }
+
+        let precision = f.precision();
+        let component = |c: &T| match precision {
+            Some(p) => format!("{:.*?}", p, c),
+            None => format!("{:?}", c),
+        };
+
+        let x = component(&self.x);
+        let y = component(&self.y);
+        let z = component(&self.z);
+        let width = x.len().max(y.len()).max(z.len());
+
+        writeln!(f, "Vec3 {{")?;
+        writeln!(f, "    x: {:>width$},", x, width = width)?;
+        writeln!(f, "    y: {:>width$},", y, width = width)?;
+        writeln!(f, "    z: {:>width$},", z, width = width)?;
+        write!(f, "}}")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec3<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "({}, {}, {})", self.x, self.y, self.z)
     }